@@ -2,5 +2,6 @@ from .core.config import Config
 from .core.instance import Ruson
 from .core.ruson_doc import RusonDoc
 from .driver.client import create_client
+from .driver.errors import *
 from .driver.results import *
 from .driver.types import *
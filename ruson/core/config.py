@@ -1,7 +1,13 @@
-from pydantic import BaseModel
+from pydantic import BaseModel, ConfigDict
+
+from ..driver.types import CodecOptions, Timeout
 
 
 class Config(BaseModel):
     connection_name: str = "default"
     database_uri: str
     database_name: str
+    timeout: int | Timeout | None = None
+    codec_options: CodecOptions | None = None
+
+    model_config = ConfigDict(arbitrary_types_allowed=True)
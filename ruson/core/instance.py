@@ -1,5 +1,6 @@
 from ..driver.client import Client, create_client
 from ..driver.session import Session
+from ..driver.types import SessionOptions
 from .config import Config
 
 
@@ -10,7 +11,9 @@ class Ruson:
     async def create_connection(cls, config: Config) -> None:
         if config.connection_name in cls._connections:
             return
-        client = await create_client(config.database_uri)
+        client = await create_client(
+            config.database_uri, config.timeout, config.codec_options
+        )
         cls._connections[config.connection_name] = (config, client)
 
     @classmethod
@@ -24,7 +27,9 @@ class Ruson:
         return config
 
     @classmethod
-    async def create_session(cls, connection_name: str = "default") -> Session:
+    async def create_session(
+        cls, connection_name: str = "default", options: SessionOptions | None = None
+    ) -> Session:
         client = cls.get_client(connection_name)
-        session = await client.create_session()
+        session = await client.create_session(options)
         return session
@@ -6,6 +6,8 @@ from pydantic import BaseModel, ConfigDict, Field, field_serializer
 
 from ..driver.collection import Collection, noop_formatter
 from ..driver.results import (
+    BulkWriteResult,
+    ChangeStream,
     CreateIndexesResult,
     DeleteResult,
     DocumentsCursor,
@@ -16,18 +18,30 @@ from ..driver.results import (
 )
 from ..driver.session import Session
 from ..driver.types import (
+    AggregateOptions,
     BaseTypes,
+    ChangeStreamOptions,
     CollectionTypes,
+    CountOptions,
+    DeleteOptions,
     Document,
     DocumentTypes,
     FieldSort,
     Filter,
+    FindOptions,
     IndexModel,
+    InsertOptions,
     ObjectId,
     Projection,
     PydanticObjectId,
+    ReturnDocument,
+    Timeout,
     Update,
+    UpdateModifications,
     UpdateOperators,
+    UpdateOptions,
+    UpdatePipeline,
+    WriteModel,
 )
 from .instance import Ruson
 
@@ -107,7 +121,10 @@ def documentify_document(document: DocumentTypes) -> Document:
     return doc
 
 
-def documentify_update(update: Update) -> Document:
+def documentify_update(update: Update | UpdatePipeline) -> UpdateModifications:
+    if isinstance(update, list):
+        return [documentify_update(stage) for stage in update]
+
     if isinstance(update, BaseModel):
         update = update.model_dump(by_alias=True, exclude_unset=True)
 
@@ -150,52 +167,60 @@ class RusonDoc(BaseModel):
     @classmethod
     async def list_indexes(
         cls: Type[Self],
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> IndexesCursor:
         collection = _get_collection(
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
-        return await collection.list_indexes(timeout=timeout)
+        return await collection.list_indexes(timeout=timeout, session=session)
 
     @classmethod
     async def create_index(
         cls: Type[Self],
         index: IndexModel,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> CreateIndexesResult:
         collection = _get_collection(
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
-        return await collection.create_indexes([index], timeout=timeout)
+        return await collection.create_indexes(
+            [index], timeout=timeout, session=session
+        )
 
     @classmethod
     async def create_indexes(
         cls: Type[Self],
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> CreateIndexesResult:
         collection = _get_collection(
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
-        return await collection.create_indexes(cls.class_indexes(), timeout=timeout)
+        return await collection.create_indexes(
+            cls.class_indexes(), timeout=timeout, session=session
+        )
 
     @classmethod
     async def drop_indexes(
         cls: Type[Self],
         indexes: list[str] | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> None:
         collection = _get_collection(
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
-        await collection.drop_indexes(indexes=indexes, timeout=timeout)
+        await collection.drop_indexes(indexes=indexes, timeout=timeout, session=session)
 
     @overload
     async def find(
@@ -205,9 +230,10 @@ class RusonDoc(BaseModel):
         sort: list[FieldSort] | None = None,
         batch_size: int | None = None,
         projection: Projection | Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> T: ...
@@ -220,9 +246,10 @@ class RusonDoc(BaseModel):
         sort: list[FieldSort] | None = None,
         batch_size: int | None = None,
         projection: Projection | Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> DocumentsCursor[T]: ...
@@ -235,9 +262,10 @@ class RusonDoc(BaseModel):
         sort: list[FieldSort] | None = None,
         batch_size: int | None = None,
         projection: Projection | Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> T: ...
@@ -248,10 +276,11 @@ class RusonDoc(BaseModel):
         sort: list[FieldSort] | None = None,
         batch_size: int | None = None,
         projection: Projection | Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         many: bool = False,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> T | DocumentsCursor[T]:
@@ -265,17 +294,20 @@ class RusonDoc(BaseModel):
                 timeout=timeout,
                 formatter=formatter,
                 session=session,
+                options=options,
                 db_name=db_name,
                 conn_name=conn_name,
             )
 
         return await self.find_one(
             filter=self,
+            skip=skip,
             sort=sort,
             projection=projection,
             timeout=timeout,
             formatter=formatter,
             session=session,
+            options=options,
             db_name=db_name,
             conn_name=conn_name,
         )
@@ -284,11 +316,13 @@ class RusonDoc(BaseModel):
     async def find_one(
         cls: Type[Self],
         filter: Filter,
+        skip: int | None = None,
         sort: list[FieldSort] | None = None,
         projection: Projection | Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> T:
@@ -302,11 +336,13 @@ class RusonDoc(BaseModel):
             projection = documentify_projection(projection)
         return await collection.find_one(
             filter=filter,
+            skip=skip,
             sort=sort,
             projection=projection,
             timeout=timeout,
             formatter=formatter,
             session=session,
+            options=options,
         )
 
     @classmethod
@@ -318,9 +354,11 @@ class RusonDoc(BaseModel):
         skip: int | None = None,
         limit: int | None = None,
         batch_size: int | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
+        prefetch: int | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> DocumentsCursor[T]:
@@ -343,6 +381,111 @@ class RusonDoc(BaseModel):
             timeout=timeout,
             formatter=formatter,
             session=session,
+            options=options,
+            prefetch=prefetch,
+        )
+
+    @classmethod
+    async def find_one_and_update(
+        cls: Type[Self],
+        update: Update | UpdatePipeline,
+        filter: Filter,
+        return_document: ReturnDocument | None = None,
+        sort: list[FieldSort] | None = None,
+        projection: Projection | Document | None = None,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> T | None:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        update = documentify_update(update)
+        filter = documentify_filter(filter)
+        if sort is not None:
+            sort = documentify_sort(sort)
+        if isinstance(projection, Projection):
+            projection = documentify_projection(projection)
+        return await collection.find_one_and_update(
+            update=update,
+            filter=filter,
+            return_document=return_document,
+            sort=sort,
+            projection=projection,
+            upsert=upsert,
+            array_filters=array_filters,
+            timeout=timeout,
+            formatter=formatter,
+            session=session,
+        )
+
+    @classmethod
+    async def find_one_and_replace(
+        cls: Type[Self],
+        replacement: DocumentTypes,
+        filter: Filter,
+        return_document: ReturnDocument | None = None,
+        sort: list[FieldSort] | None = None,
+        projection: Projection | Document | None = None,
+        upsert: bool | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> T | None:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        replacement = documentify_document(replacement)
+        filter = documentify_filter(filter)
+        if sort is not None:
+            sort = documentify_sort(sort)
+        if isinstance(projection, Projection):
+            projection = documentify_projection(projection)
+        return await collection.find_one_and_replace(
+            replacement=replacement,
+            filter=filter,
+            return_document=return_document,
+            sort=sort,
+            projection=projection,
+            upsert=upsert,
+            timeout=timeout,
+            formatter=formatter,
+            session=session,
+        )
+
+    @classmethod
+    async def find_one_and_delete(
+        cls: Type[Self],
+        filter: Filter,
+        sort: list[FieldSort] | None = None,
+        projection: Projection | Document | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> T | None:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        filter = documentify_filter(filter)
+        if sort is not None:
+            sort = documentify_sort(sort)
+        if isinstance(projection, Projection):
+            projection = documentify_projection(projection)
+        return await collection.find_one_and_delete(
+            filter=filter,
+            sort=sort,
+            projection=projection,
+            timeout=timeout,
+            formatter=formatter,
+            session=session,
         )
 
     @classmethod
@@ -350,9 +493,11 @@ class RusonDoc(BaseModel):
         cls: Type[Self],
         pipeline: list[Document],
         batch_size: int | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: AggregateOptions | None = None,
+        prefetch: int | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> DocumentsCursor[T]:
@@ -365,6 +510,24 @@ class RusonDoc(BaseModel):
             timeout=timeout,
             formatter=formatter,
             session=session,
+            options=options,
+            prefetch=prefetch,
+        )
+
+    @classmethod
+    async def watch(
+        cls: Type[Self],
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> ChangeStream:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        return await collection.watch(
+            pipeline=pipeline, options=options, session=session
         )
 
     @classmethod
@@ -372,7 +535,7 @@ class RusonDoc(BaseModel):
         cls: Type[Self],
         field_name: str,
         filter: Filter | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         session: Session | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
@@ -393,7 +556,11 @@ class RusonDoc(BaseModel):
     async def count_documents(
         cls: Type[Self],
         filter: Filter | None = None,
-        timeout: int | None = None,
+        skip: int | None = None,
+        limit: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
+        options: CountOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> int:
@@ -402,17 +569,38 @@ class RusonDoc(BaseModel):
         )
         if filter is not None:
             filter = documentify_filter(filter)
-        return await collection.count_documents(filter=filter, timeout=timeout)
+        return await collection.count_documents(
+            filter=filter,
+            skip=skip,
+            limit=limit,
+            timeout=timeout,
+            session=session,
+            options=options,
+        )
+
+    @classmethod
+    async def estimated_document_count(
+        cls: Type[Self],
+        timeout: int | Timeout | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> int:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        return await collection.estimated_document_count(timeout=timeout)
 
     async def insert(
         self: Self,
         session: Session | None = None,
+        options: InsertOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> InsertOneResult:
         return await self.insert_one(
             document=self,
             session=session,
+            options=options,
             db_name=db_name,
             conn_name=conn_name,
         )
@@ -422,6 +610,7 @@ class RusonDoc(BaseModel):
         cls: Type[Self],
         document: DocumentTypes,
         session: Session | None = None,
+        options: InsertOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> InsertOneResult:
@@ -429,13 +618,16 @@ class RusonDoc(BaseModel):
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
         document = documentify_document(document)
-        return await collection.insert_one(document=document, session=session)
+        return await collection.insert_one(
+            document=document, session=session, options=options
+        )
 
     @classmethod
     async def insert_many(
         cls: Type[Self],
         documents: list[DocumentTypes],
         session: Session | None = None,
+        options: InsertOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> InsertManyResult:
@@ -443,7 +635,9 @@ class RusonDoc(BaseModel):
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
         documents = [documentify_document(document) for document in documents]
-        return await collection.insert_many(documents=documents, session=session)
+        return await collection.insert_many(
+            documents=documents, session=session, options=options
+        )
 
     async def update(
         self: Self,
@@ -451,6 +645,7 @@ class RusonDoc(BaseModel):
         operator: UpdateOperators | None = None,
         array_filters: list[Document] | None = None,
         session: Session | None = None,
+        options: UpdateOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> UpdateResult:
@@ -471,15 +666,17 @@ class RusonDoc(BaseModel):
             filter=filter,
             array_filters=array_filters,
             session=session,
+            options=options,
         )
 
     @classmethod
     async def update_one(
         cls: Type[Self],
-        update: Update,
+        update: Update | UpdatePipeline,
         filter: Filter,
         array_filters: list[Document] | None = None,
         session: Session | None = None,
+        options: UpdateOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> UpdateResult:
@@ -493,6 +690,76 @@ class RusonDoc(BaseModel):
             filter=filter,
             array_filters=array_filters,
             session=session,
+            options=options,
+        )
+
+    @classmethod
+    async def update_many(
+        cls: Type[Self],
+        update: Update | UpdatePipeline,
+        filter: Filter,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> UpdateResult:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        update = documentify_update(update)
+        filter = documentify_filter(filter)
+        return await collection.update_many(
+            update=update,
+            filter=filter,
+            upsert=upsert,
+            array_filters=array_filters,
+            session=session,
+            options=options,
+        )
+
+    async def replace(
+        self: Self,
+        filter: Filter,
+        upsert: bool | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> UpdateResult:
+        return await self.replace_one(
+            replacement=self.model_dump(by_alias=True, exclude={"id"}),
+            filter=filter,
+            upsert=upsert,
+            session=session,
+            options=options,
+            db_name=db_name,
+            conn_name=conn_name,
+        )
+
+    @classmethod
+    async def replace_one(
+        cls: Type[Self],
+        replacement: DocumentTypes,
+        filter: Filter,
+        upsert: bool | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> UpdateResult:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        replacement = documentify_document(replacement)
+        filter = documentify_filter(filter)
+        return await collection.replace_one(
+            replacement=replacement,
+            filter=filter,
+            upsert=upsert,
+            session=session,
+            options=options,
         )
 
     async def upsert(
@@ -500,6 +767,7 @@ class RusonDoc(BaseModel):
         filter: Filter,
         array_filters: list[Document] | None = None,
         session: Session | None = None,
+        options: UpdateOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> UpdateResult:
@@ -509,6 +777,7 @@ class RusonDoc(BaseModel):
             filter=filter,
             array_filters=array_filters,
             session=session,
+            options=options,
             db_name=db_name,
             conn_name=conn_name,
         )
@@ -516,10 +785,11 @@ class RusonDoc(BaseModel):
     @classmethod
     async def upsert_one(
         cls: Type[Self],
-        update: Update,
+        update: Update | UpdatePipeline,
         filter: Filter,
         array_filters: list[Document] | None = None,
         session: Session | None = None,
+        options: UpdateOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> UpdateResult:
@@ -534,12 +804,14 @@ class RusonDoc(BaseModel):
             upsert=True,
             array_filters=array_filters,
             session=session,
+            options=options,
         )
 
     async def delete(
         self: Self,
         many: bool = False,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> DeleteResult:
@@ -547,6 +819,7 @@ class RusonDoc(BaseModel):
             return await self.delete_many(
                 filter=self,
                 session=session,
+                options=options,
                 db_name=db_name,
                 conn_name=conn_name,
             )
@@ -554,6 +827,7 @@ class RusonDoc(BaseModel):
         return await self.delete_one(
             filter=self,
             session=session,
+            options=options,
             db_name=db_name,
             conn_name=conn_name,
         )
@@ -563,6 +837,7 @@ class RusonDoc(BaseModel):
         cls: Type[Self],
         filter: Filter,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> DeleteResult:
@@ -570,13 +845,16 @@ class RusonDoc(BaseModel):
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
         filter = documentify_filter(filter)
-        return await collection.delete_one(filter=filter, session=session)
+        return await collection.delete_one(
+            filter=filter, session=session, options=options
+        )
 
     @classmethod
     async def delete_many(
         cls: Type[Self],
         filter: Filter,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> DeleteResult:
@@ -584,15 +862,34 @@ class RusonDoc(BaseModel):
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
         filter = documentify_filter(filter)
-        return await collection.delete_many(filter=filter, session=session)
+        return await collection.delete_many(
+            filter=filter, session=session, options=options
+        )
+
+    @classmethod
+    async def bulk_write(
+        cls: Type[Self],
+        requests: list[WriteModel],
+        ordered: bool | None = None,
+        session: Session | None = None,
+        db_name: str | None = None,
+        conn_name: str | None = None,
+    ) -> BulkWriteResult:
+        collection = _get_collection(
+            cls.__name__.lower(), db_name=db_name, conn_name=conn_name
+        )
+        return await collection.bulk_write(
+            requests=requests, ordered=ordered, session=session
+        )
 
     @classmethod
     async def drop(
         cls: Type[Self],
+        session: Session | None = None,
         db_name: str | None = None,
         conn_name: str | None = None,
     ) -> None:
         collection = _get_collection(
             cls.__name__.lower(), db_name=db_name, conn_name=conn_name
         )
-        await collection.drop()
+        await collection.drop(session=session)
@@ -1,6 +1,15 @@
 from ..ruson import bindings
+from .collection import Collection
 from .database import Database
+from .results import ChangeStream, CheckpointedChangeStream
 from .session import Session
+from .types import (
+    ChangeStreamOptions,
+    CodecOptions,
+    Document,
+    SessionOptions,
+    Timeout,
+)
 
 rust_client = bindings.client
 
@@ -9,20 +18,70 @@ class Client:
     def __init__(self, binding_client):
         self.__binding_client = binding_client
 
-    def database(self, database_name: str) -> Database:
-        return Database(rust_client.database(self.__binding_client, database_name))
+    def database(
+        self,
+        database_name: str,
+        timeout: int | Timeout | None = None,
+        codec_options: CodecOptions | None = None,
+    ) -> Database:
+        return Database(
+            rust_client.database(
+                self.__binding_client, database_name, timeout, codec_options
+            )
+        )
 
-    def default_database(self) -> Database | None:
+    def default_database(
+        self,
+        timeout: int | Timeout | None = None,
+        codec_options: CodecOptions | None = None,
+    ) -> Database | None:
         try:
-            return Database(rust_client.default_database(self.__binding_client))
+            return Database(
+                rust_client.default_database(
+                    self.__binding_client, timeout, codec_options
+                )
+            )
         except:
             return None
 
-    async def list_databases(self) -> list[str]:
-        return await rust_client.list_database_names(self.__binding_client)
+    async def list_databases(self, session: Session | None = None) -> list[str]:
+        s = None if session is None else session._get_session()
+        return await rust_client.list_database_names(self.__binding_client, s)
 
-    async def create_session(self) -> Session:
-        return Session(await rust_client.create_session(self.__binding_client))
+    async def watch(
+        self,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+    ) -> ChangeStream:
+        s = None if session is None else session._get_session()
+        return await rust_client.watch(self.__binding_client, pipeline, options, s)
+
+    async def watch_with_checkpoints(
+        self,
+        checkpoints: Collection,
+        consumer_name: str,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        checkpoint_every: int | None = 100,
+        checkpoint_interval: int | None = 10_000,
+        max_retries: int | None = None,
+    ) -> CheckpointedChangeStream:
+        return await rust_client.watch_with_checkpoints(
+            self.__binding_client,
+            checkpoints._get_collection(),
+            consumer_name,
+            pipeline,
+            options,
+            checkpoint_every,
+            checkpoint_interval,
+            max_retries,
+        )
+
+    async def create_session(self, options: SessionOptions | None = None) -> Session:
+        return Session(
+            await rust_client.create_session(self.__binding_client, options)
+        )
 
     async def shutdown(self) -> None:
         await rust_client.shutdown(self.__binding_client)
@@ -31,5 +90,9 @@ class Client:
         return self.database(database_name)
 
 
-async def create_client(db_uri: str) -> Client:
-    return Client(await rust_client.create_client(db_uri))
+async def create_client(
+    db_uri: str,
+    timeout: int | Timeout | None = None,
+    codec_options: CodecOptions | None = None,
+) -> Client:
+    return Client(await rust_client.create_client(db_uri, timeout, codec_options))
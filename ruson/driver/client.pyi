@@ -1,11 +1,51 @@
+from .collection import Collection
 from .database import Database
+from .results import ChangeStream, CheckpointedChangeStream
 from .session import Session
+from .types import (
+    ChangeStreamOptions,
+    CodecOptions,
+    Document,
+    SessionOptions,
+    Timeout,
+)
 
 class Client:
-    def database(self, database_name: str) -> Database: ...
-    def default_database(self) -> Database | None: ...
-    async def list_databases(self) -> list[str]: ...
-    async def create_session(self) -> Session: ...
+    def database(
+        self,
+        database_name: str,
+        timeout: int | Timeout | None = None,
+        codec_options: CodecOptions | None = None,
+    ) -> Database: ...
+    def default_database(
+        self,
+        timeout: int | Timeout | None = None,
+        codec_options: CodecOptions | None = None,
+    ) -> Database | None: ...
+    async def list_databases(self, session: Session | None = None) -> list[str]: ...
+    async def watch(
+        self,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+    ) -> ChangeStream: ...
+    async def watch_with_checkpoints(
+        self,
+        checkpoints: Collection,
+        consumer_name: str,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        checkpoint_every: int | None = 100,
+        checkpoint_interval: int | None = 10_000,
+        max_retries: int | None = None,
+    ) -> CheckpointedChangeStream: ...
+    async def create_session(
+        self, options: SessionOptions | None = None
+    ) -> Session: ...
     async def shutdown(self) -> None: ...
 
-async def create_client(db_uri: str) -> Client: ...
+async def create_client(
+    db_uri: str,
+    timeout: int | Timeout | None = None,
+    codec_options: CodecOptions | None = None,
+) -> Client: ...
@@ -2,6 +2,9 @@ from typing import Awaitable, Callable, TypeVar
 
 from ..ruson import bindings
 from .results import (
+    BulkWriteResult,
+    ChangeStream,
+    CheckpointedChangeStream,
     CreateIndexesResult,
     DeleteResult,
     DocumentsCursor,
@@ -11,7 +14,21 @@ from .results import (
     UpdateResult,
 )
 from .session import Session
-from .types import Document, IndexModel
+from .types import (
+    AggregateOptions,
+    ChangeStreamOptions,
+    CountOptions,
+    DeleteOptions,
+    Document,
+    FindOptions,
+    IndexModel,
+    InsertOptions,
+    ReturnDocument,
+    Timeout,
+    UpdateModifications,
+    UpdateOptions,
+    WriteModel,
+)
 
 rust_collection = bindings.collection
 
@@ -26,18 +43,30 @@ class Collection:
     def __init__(self, binding_collection):
         self.__binding_collection = binding_collection
 
+    def _get_collection(self):
+        return self.__binding_collection
+
     async def find_one(
         self,
         filter: Document,
+        skip: int | None = None,
         sort: Document | None = None,
         projection: Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
     ) -> Document:
         s = None if session is None else session._get_session()
         result = await rust_collection.find_one(
-            self.__binding_collection, filter, sort, projection, timeout, s
+            self.__binding_collection,
+            filter,
+            skip,
+            sort,
+            projection,
+            options,
+            timeout,
+            s,
         )
         if result is None:
             raise ValueError("Document not found")
@@ -55,9 +84,11 @@ class Collection:
         sort: Document | None = None,
         batch_size: int | None = None,
         projection: Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
+        prefetch: int | None = None,
     ) -> DocumentsCursor[T]:
         s = None if session is None else session._get_session()
         cursor = await rust_collection.find_many(
@@ -68,76 +99,267 @@ class Collection:
             sort,
             batch_size,
             projection,
+            options,
             timeout,
             s,
+            prefetch,
         )
         return DocumentsCursor(cursor, formatter)
 
+    async def find_one_and_update(
+        self,
+        update: UpdateModifications,
+        filter: Document,
+        return_document: ReturnDocument | None = None,
+        sort: Document | None = None,
+        projection: Document | None = None,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+    ) -> T | None:
+        s = None if session is None else session._get_session()
+        result = await rust_collection.find_one_and_update(
+            self.__binding_collection,
+            update,
+            filter,
+            return_document,
+            sort,
+            projection,
+            upsert,
+            array_filters,
+            timeout,
+            s,
+        )
+        if result is None:
+            return None
+
+        formatted = formatter(result)
+        if isinstance(formatted, Awaitable):
+            return await formatted
+        return formatted
+
+    async def find_one_and_replace(
+        self,
+        replacement: Document,
+        filter: Document,
+        return_document: ReturnDocument | None = None,
+        sort: Document | None = None,
+        projection: Document | None = None,
+        upsert: bool | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+    ) -> T | None:
+        s = None if session is None else session._get_session()
+        result = await rust_collection.find_one_and_replace(
+            self.__binding_collection,
+            replacement,
+            filter,
+            return_document,
+            sort,
+            projection,
+            upsert,
+            timeout,
+            s,
+        )
+        if result is None:
+            return None
+
+        formatted = formatter(result)
+        if isinstance(formatted, Awaitable):
+            return await formatted
+        return formatted
+
+    async def find_one_and_delete(
+        self,
+        filter: Document,
+        sort: Document | None = None,
+        projection: Document | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+    ) -> T | None:
+        s = None if session is None else session._get_session()
+        result = await rust_collection.find_one_and_delete(
+            self.__binding_collection, filter, sort, projection, timeout, s
+        )
+        if result is None:
+            return None
+
+        formatted = formatter(result)
+        if isinstance(formatted, Awaitable):
+            return await formatted
+        return formatted
+
     async def insert_one(
         self,
         document: Document,
         session: Session | None = None,
+        options: InsertOptions | None = None,
     ) -> InsertOneResult:
         s = None if session is None else session._get_session()
-        return await rust_collection.insert_one(self.__binding_collection, document, s)
+        return await rust_collection.insert_one(
+            self.__binding_collection, document, options, s
+        )
 
     async def insert_many(
         self,
         documents: list[Document],
         session: Session | None = None,
+        options: InsertOptions | None = None,
     ) -> InsertManyResult:
         s = None if session is None else session._get_session()
         return await rust_collection.insert_many(
-            self.__binding_collection, documents, s
+            self.__binding_collection, documents, options, s
         )
 
     async def update_one(
         self,
-        update: Document,
+        update: UpdateModifications,
         filter: Document,
         upsert: bool | None = None,
         array_filters: list[Document] | None = None,
         session: Session | None = None,
+        options: UpdateOptions | None = None,
     ) -> UpdateResult:
         s = None if session is None else session._get_session()
         return await rust_collection.update_one(
-            self.__binding_collection, update, filter, upsert, array_filters, s
+            self.__binding_collection,
+            update,
+            filter,
+            upsert,
+            array_filters,
+            options,
+            s,
+        )
+
+    async def update_many(
+        self,
+        update: UpdateModifications,
+        filter: Document,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
+    ) -> UpdateResult:
+        s = None if session is None else session._get_session()
+        return await rust_collection.update_many(
+            self.__binding_collection,
+            update,
+            filter,
+            upsert,
+            array_filters,
+            options,
+            s,
+        )
+
+    async def replace_one(
+        self,
+        replacement: Document,
+        filter: Document,
+        upsert: bool | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
+    ) -> UpdateResult:
+        s = None if session is None else session._get_session()
+        return await rust_collection.replace_one(
+            self.__binding_collection, replacement, filter, upsert, options, s
         )
 
     async def delete_one(
         self,
         filter: Document,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
     ) -> DeleteResult:
         s = None if session is None else session._get_session()
-        return await rust_collection.delete_one(self.__binding_collection, filter, s)
+        return await rust_collection.delete_one(
+            self.__binding_collection, filter, options, s
+        )
 
     async def delete_many(
         self,
         filter: Document | None = None,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
     ) -> DeleteResult:
         s = None if session is None else session._get_session()
-        return await rust_collection.delete_many(self.__binding_collection, filter, s)
+        return await rust_collection.delete_many(
+            self.__binding_collection, filter, options, s
+        )
+
+    async def bulk_write(
+        self,
+        requests: list[WriteModel],
+        ordered: bool | None = None,
+        session: Session | None = None,
+    ) -> BulkWriteResult:
+        s = None if session is None else session._get_session()
+        return await rust_collection.bulk_write(
+            self.__binding_collection, requests, ordered, s
+        )
 
     async def aggregate(
         self,
         pipeline: list[Document],
         batch_size: int | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
-    ) -> DocumentsCursor:
+        options: AggregateOptions | None = None,
+        prefetch: int | None = None,
+    ) -> DocumentsCursor[T]:
         s = None if session is None else session._get_session()
         cursor = await rust_collection.aggregate(
-            self.__binding_collection, pipeline, batch_size, timeout, s
+            self.__binding_collection,
+            pipeline,
+            batch_size,
+            options,
+            timeout,
+            s,
+            prefetch,
+        )
+        return DocumentsCursor(cursor, formatter)
+
+    async def watch(
+        self,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+    ) -> ChangeStream:
+        s = None if session is None else session._get_session()
+        return await rust_collection.watch(
+            self.__binding_collection, pipeline, options, s
+        )
+
+    async def watch_with_checkpoints(
+        self,
+        checkpoints: "Collection",
+        consumer_name: str,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        checkpoint_every: int | None = 100,
+        checkpoint_interval: int | None = 10_000,
+        max_retries: int | None = None,
+    ) -> CheckpointedChangeStream:
+        return await rust_collection.watch_with_checkpoints(
+            self.__binding_collection,
+            checkpoints._get_collection(),
+            consumer_name,
+            pipeline,
+            options,
+            checkpoint_every,
+            checkpoint_interval,
+            max_retries,
         )
-        return DocumentsCursor(cursor)
 
     async def distinct(
         self,
         field_name: str,
         filter: Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         session: Session | None = None,
     ) -> list[str]:
         s = None if session is None else session._get_session()
@@ -147,35 +369,58 @@ class Collection:
 
     async def list_indexes(
         self,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
     ) -> IndexesCursor:
-        cursor = await rust_collection.list_indexes(self.__binding_collection, timeout)
+        s = None if session is None else session._get_session()
+        cursor = await rust_collection.list_indexes(
+            self.__binding_collection, timeout, s
+        )
         return IndexesCursor(cursor)
 
     async def create_indexes(
         self,
         indexes: list[IndexModel],
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
     ) -> CreateIndexesResult:
+        s = None if session is None else session._get_session()
         return await rust_collection.create_indexes(
-            self.__binding_collection, indexes, timeout
+            self.__binding_collection, indexes, timeout, s
         )
 
     async def drop_indexes(
         self,
         indexes: list[str] | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
     ) -> None:
-        await rust_collection.drop_indexes(self.__binding_collection, indexes, timeout)
+        s = None if session is None else session._get_session()
+        await rust_collection.drop_indexes(
+            self.__binding_collection, indexes, timeout, s
+        )
 
     async def count_documents(
         self,
         filter: Document | None = None,
-        timeout: int | None = None,
+        skip: int | None = None,
+        limit: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
+        options: CountOptions | None = None,
     ) -> int:
+        s = None if session is None else session._get_session()
         return await rust_collection.count_documents(
-            self.__binding_collection, filter, timeout
+            self.__binding_collection, filter, skip, limit, options, timeout, s
         )
 
-    async def drop(self) -> None:
-        await rust_collection.drop(self.__binding_collection)
+    async def estimated_document_count(
+        self, timeout: int | Timeout | None = None
+    ) -> int:
+        return await rust_collection.estimated_document_count(
+            self.__binding_collection, timeout
+        )
+
+    async def drop(self, session: Session | None = None) -> None:
+        s = None if session is None else session._get_session()
+        await rust_collection.drop(self.__binding_collection, s)
@@ -1,6 +1,9 @@
 from typing import Awaitable, Callable, TypeVar
 
 from .results import (
+    BulkWriteResult,
+    ChangeStream,
+    CheckpointedChangeStream,
     CreateIndexesResult,
     DeleteResult,
     DocumentsCursor,
@@ -10,7 +13,21 @@ from .results import (
     UpdateResult,
 )
 from .session import Session
-from .types import Document, IndexModel
+from .types import (
+    AggregateOptions,
+    ChangeStreamOptions,
+    CountOptions,
+    DeleteOptions,
+    Document,
+    FindOptions,
+    IndexModel,
+    InsertOptions,
+    ReturnDocument,
+    Timeout,
+    UpdateModifications,
+    UpdateOptions,
+    WriteModel,
+)
 
 T = TypeVar("T")
 
@@ -23,9 +40,10 @@ class Collection:
         skip: int | None = None,
         sort: Document | None = None,
         projection: Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
     ) -> Document: ...
     async def find_many(
         self,
@@ -35,69 +53,162 @@ class Collection:
         sort: Document | None = None,
         batch_size: int | None = None,
         projection: Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: FindOptions | None = None,
+        prefetch: int | None = None,
     ) -> DocumentsCursor: ...
+    async def find_one_and_update(
+        self,
+        update: UpdateModifications,
+        filter: Document,
+        return_document: ReturnDocument | None = None,
+        sort: Document | None = None,
+        projection: Document | None = None,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+    ) -> T | None: ...
+    async def find_one_and_replace(
+        self,
+        replacement: Document,
+        filter: Document,
+        return_document: ReturnDocument | None = None,
+        sort: Document | None = None,
+        projection: Document | None = None,
+        upsert: bool | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+    ) -> T | None: ...
+    async def find_one_and_delete(
+        self,
+        filter: Document,
+        sort: Document | None = None,
+        projection: Document | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
+        session: Session | None = None,
+    ) -> T | None: ...
     async def insert_one(
         self,
         document: Document,
         session: Session | None = None,
+        options: InsertOptions | None = None,
     ) -> InsertOneResult: ...
     async def insert_many(
         self,
         documents: list[Document],
         session: Session | None = None,
+        options: InsertOptions | None = None,
     ) -> InsertManyResult: ...
     async def update_one(
         self,
-        update: Document,
+        update: UpdateModifications,
+        filter: Document,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
+    ) -> UpdateResult: ...
+    async def update_many(
+        self,
+        update: UpdateModifications,
         filter: Document,
         upsert: bool | None = None,
         array_filters: list[Document] | None = None,
         session: Session | None = None,
+        options: UpdateOptions | None = None,
+    ) -> UpdateResult: ...
+    async def replace_one(
+        self,
+        replacement: Document,
+        filter: Document,
+        upsert: bool | None = None,
+        session: Session | None = None,
+        options: UpdateOptions | None = None,
     ) -> UpdateResult: ...
     async def delete_one(
         self,
         filter: Document,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
     ) -> DeleteResult: ...
     async def delete_many(
         self,
         filter: Document | None = None,
         session: Session | None = None,
+        options: DeleteOptions | None = None,
     ) -> DeleteResult: ...
+    async def bulk_write(
+        self,
+        requests: list[WriteModel],
+        ordered: bool | None = None,
+        session: Session | None = None,
+    ) -> BulkWriteResult: ...
     async def aggregate(
         self,
         pipeline: list[Document],
         batch_size: int | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        formatter: Callable[[Document], T | Awaitable[T]] = noop_formatter,
         session: Session | None = None,
+        options: AggregateOptions | None = None,
+        prefetch: int | None = None,
     ) -> DocumentsCursor: ...
+    async def watch(
+        self,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+    ) -> ChangeStream: ...
+    async def watch_with_checkpoints(
+        self,
+        checkpoints: Collection,
+        consumer_name: str,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        checkpoint_every: int | None = 100,
+        checkpoint_interval: int | None = 10_000,
+        max_retries: int | None = None,
+    ) -> CheckpointedChangeStream: ...
     async def distinct(
         self,
         field_name: str,
         filter: Document | None = None,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
         session: Session | None = None,
     ) -> list[str]: ...
     async def list_indexes(
         self,
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
     ) -> IndexesCursor: ...
     async def create_indexes(
         self,
         indexes: list[IndexModel],
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
     ) -> CreateIndexesResult: ...
     async def drop_indexes(
         self,
         indexes: list[str],
-        timeout: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
     ) -> None: ...
     async def count_documents(
         self,
         filter: Document | None = None,
-        timeout: int | None = None,
+        skip: int | None = None,
+        limit: int | None = None,
+        timeout: int | Timeout | None = None,
+        session: Session | None = None,
+        options: CountOptions | None = None,
+    ) -> int: ...
+    async def estimated_document_count(
+        self, timeout: int | Timeout | None = None
     ) -> int: ...
-    async def drop(self) -> None: ...
+    async def drop(self, session: Session | None = None) -> None: ...
@@ -1,5 +1,8 @@
 from ..ruson import bindings
 from .collection import Collection
+from .results import ChangeStream, CheckpointedChangeStream
+from .session import Session
+from .types import ChangeStreamOptions, CodecOptions, Document, Timeout
 
 rust_database = bindings.database
 
@@ -8,16 +11,69 @@ class Database:
     def __init__(self, binding_database):
         self.__binding_database = binding_database
 
-    def collection(self, collection_name: str) -> Collection:
+    def collection(
+        self,
+        collection_name: str,
+        timeout: int | Timeout | None = None,
+        codec_options: CodecOptions | None = None,
+    ) -> Collection:
         return Collection(
-            rust_database.collection(self.__binding_database, collection_name)
+            rust_database.collection(
+                self.__binding_database, collection_name, timeout, codec_options
+            )
         )
 
-    async def list_collections(self) -> list[str]:
-        return await rust_database.list_collections(self.__binding_database)
+    async def list_collections(self, session: Session | None = None) -> list[str]:
+        s = None if session is None else session._get_session()
+        return await rust_database.list_collections(self.__binding_database, s)
 
-    async def drop(self) -> None:
-        await rust_database.drop(self.__binding_database)
+    async def create_collection(
+        self,
+        collection_name: str,
+        capped: bool = False,
+        size: int | None = None,
+        max_documents: int | None = None,
+        session: Session | None = None,
+    ) -> Collection:
+        s = None if session is None else session._get_session()
+        await rust_database.create_collection(
+            self.__binding_database, collection_name, capped, size, max_documents, s
+        )
+        return self.collection(collection_name)
+
+    async def drop(self, session: Session | None = None) -> None:
+        s = None if session is None else session._get_session()
+        await rust_database.drop(self.__binding_database, s)
+
+    async def watch(
+        self,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+    ) -> ChangeStream:
+        s = None if session is None else session._get_session()
+        return await rust_database.watch(self.__binding_database, pipeline, options, s)
+
+    async def watch_with_checkpoints(
+        self,
+        checkpoints: Collection,
+        consumer_name: str,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        checkpoint_every: int | None = 100,
+        checkpoint_interval: int | None = 10_000,
+        max_retries: int | None = None,
+    ) -> CheckpointedChangeStream:
+        return await rust_database.watch_with_checkpoints(
+            self.__binding_database,
+            checkpoints._get_collection(),
+            consumer_name,
+            pipeline,
+            options,
+            checkpoint_every,
+            checkpoint_interval,
+            max_retries,
+        )
 
     def __getitem__(self, collection_name: str) -> Collection:
         return self.collection(collection_name)
@@ -1,6 +1,38 @@
 from .collection import Collection
+from .results import ChangeStream, CheckpointedChangeStream
+from .session import Session
+from .types import ChangeStreamOptions, CodecOptions, Document, Timeout
 
 class Database:
-    def collection(self, collection_name: str) -> Collection: ...
-    async def list_collections(self) -> list[str]: ...
-    async def drop(self) -> None: ...
+    def collection(
+        self,
+        collection_name: str,
+        timeout: int | Timeout | None = None,
+        codec_options: CodecOptions | None = None,
+    ) -> Collection: ...
+    async def list_collections(self, session: Session | None = None) -> list[str]: ...
+    async def create_collection(
+        self,
+        collection_name: str,
+        capped: bool = False,
+        size: int | None = None,
+        max_documents: int | None = None,
+        session: Session | None = None,
+    ) -> Collection: ...
+    async def drop(self, session: Session | None = None) -> None: ...
+    async def watch(
+        self,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        session: Session | None = None,
+    ) -> ChangeStream: ...
+    async def watch_with_checkpoints(
+        self,
+        checkpoints: Collection,
+        consumer_name: str,
+        pipeline: list[Document] | None = None,
+        options: ChangeStreamOptions | None = None,
+        checkpoint_every: int | None = 100,
+        checkpoint_interval: int | None = 10_000,
+        max_retries: int | None = None,
+    ) -> CheckpointedChangeStream: ...
@@ -0,0 +1,13 @@
+from ..ruson import bindings
+
+RusonError = bindings.errors.RusonError
+OperationFailure = bindings.errors.OperationFailure
+WriteError = bindings.errors.WriteError
+DuplicateKeyError = bindings.errors.DuplicateKeyError
+DocumentValidationError = bindings.errors.DocumentValidationError
+WriteConcernError = bindings.errors.WriteConcernError
+BulkWriteError = bindings.errors.BulkWriteError
+OperationTimeout = bindings.errors.OperationTimeout
+ConnectionFailure = bindings.errors.ConnectionFailure
+ServerSelectionTimeout = bindings.errors.ServerSelectionTimeout
+TransactionError = bindings.errors.TransactionError
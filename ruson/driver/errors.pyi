@@ -0,0 +1,25 @@
+from .results import BulkWriteResult, WriteConcernErrorResult, WriteErrorResult
+from .types import BaseTypes, Document
+
+class RusonError(ValueError):
+    code: int | None
+    code_name: str | None
+    error_labels: list[str]
+    details: Document | None
+
+class OperationFailure(RusonError): ...
+class WriteError(OperationFailure): ...
+class DuplicateKeyError(WriteError): ...
+class DocumentValidationError(WriteError): ...
+class WriteConcernError(OperationFailure): ...
+
+class BulkWriteError(OperationFailure):
+    write_errors: list[WriteErrorResult]
+    write_concern_errors: list[WriteConcernErrorResult]
+    inserted_ids: dict[int, BaseTypes]
+    result: BulkWriteResult | None
+
+class OperationTimeout(OperationFailure): ...
+class ConnectionFailure(RusonError): ...
+class ServerSelectionTimeout(ConnectionFailure): ...
+class TransactionError(RusonError): ...
@@ -8,6 +8,15 @@ InsertManyResult = bindings.types.InsertManyResult
 UpdateResult = bindings.types.UpdateResult
 DeleteResult = bindings.types.DeleteResult
 CreateIndexesResult = bindings.types.CreateIndexesResult
+BulkWriteResult = bindings.types.BulkWriteResult
+WriteErrorResult = bindings.types.WriteErrorResult
+WriteConcernErrorResult = bindings.types.WriteConcernErrorResult
+ChangeStream = bindings.types.ChangeStream
+CheckpointedChangeStream = bindings.types.CheckpointedChangeStream
+ChangeEvent = bindings.types.ChangeEvent
+ChangeNamespace = bindings.types.ChangeNamespace
+UpdateDescription = bindings.types.UpdateDescription
+TruncatedArray = bindings.types.TruncatedArray
 
 T = TypeVar("T")
 
@@ -19,32 +28,53 @@ class DocumentsCursor(Generic[T]):
         self.__binding_iterator = binding_iterator
         self.__formatter = formatter
 
+    async def __format(self, document: Document) -> T:
+        formatted = self.__formatter(document)
+        if isinstance(formatted, Awaitable):
+            return await formatted
+        return formatted
+
+    async def next_batch(self, n: int) -> list[T]:
+        documents = await self.__binding_iterator.next_batch(n)
+        return [await self.__format(document) for document in documents]
+
+    async def to_list(
+        self, max_length: int | None = None, max_bytes: int | None = None
+    ) -> list[T]:
+        documents = await self.__binding_iterator.to_list(max_length, max_bytes)
+        return [await self.__format(document) for document in documents]
+
     async def tolist(self) -> list[T]:
         result = []
         async for item in self:
             result.append(item)
         return result
 
-    def __aiter__(self) -> Self:
-        return self
+    @property
+    def id(self) -> int:
+        return self.__binding_iterator.id
 
-    async def __anext__(self) -> T:
-        try:
-            result = await bindings.iterator.document_advance(self.__binding_iterator)
-        except:
-            raise StopAsyncIteration
+    @property
+    def alive(self) -> bool:
+        return self.__binding_iterator.alive
 
-        if not result:
-            raise StopAsyncIteration
+    async def has_next(self) -> bool:
+        return await self.__binding_iterator.has_next()
 
-        result = await bindings.iterator.document_current(self.__binding_iterator)
-        if result is None:
-            raise StopAsyncIteration
+    async def close(self) -> None:
+        await self.__binding_iterator.close()
 
-        formatted = self.__formatter(result)
-        if isinstance(formatted, Awaitable):
-            return await formatted
-        return formatted
+    async def __aenter__(self) -> Self:
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback) -> None:
+        await self.close()
+
+    def __aiter__(self) -> Self:
+        return self
+
+    async def __anext__(self) -> T:
+        return await self.__format(await anext(self.__binding_iterator))
 
 
 class IndexesCursor:
@@ -57,19 +87,28 @@ class IndexesCursor:
             result.append(item)
         return result
 
-    def __aiter__(self) -> Self:
+    @property
+    def id(self) -> int:
+        return self.__binding_iterator.id
+
+    @property
+    def alive(self) -> bool:
+        return self.__binding_iterator.alive
+
+    async def has_next(self) -> bool:
+        return await self.__binding_iterator.has_next()
+
+    async def close(self) -> None:
+        await self.__binding_iterator.close()
+
+    async def __aenter__(self) -> Self:
         return self
 
-    async def __anext__(self) -> IndexModel:
-        try:
-            result = await bindings.iterator.index_advance(self.__binding_iterator)
-        except:
-            raise StopAsyncIteration
+    async def __aexit__(self, exc_type, exc_value, traceback) -> None:
+        await self.close()
 
-        if not result:
-            raise StopAsyncIteration
+    def __aiter__(self) -> Self:
+        return self
 
-        result = await bindings.iterator.index_current(self.__binding_iterator)
-        if not result:
-            raise StopAsyncIteration
-        return result
+    async def __anext__(self) -> IndexModel:
+        return await anext(self.__binding_iterator)
@@ -1,6 +1,8 @@
+from datetime import datetime
+from types import TracebackType
 from typing import Generic, Self, TypeVar
 
-from .types import BaseTypes, IndexModel
+from .types import BaseTypes, Document, IndexModel, Timestamp
 
 T = TypeVar("T")
 
@@ -28,12 +30,162 @@ class CreateIndexesResult:
     @property
     def index_names(self) -> list[str]: ...
 
+class WriteErrorResult:
+    @property
+    def index(self) -> int: ...
+    @property
+    def code(self) -> int: ...
+    @property
+    def code_name(self) -> str | None: ...
+    @property
+    def message(self) -> str: ...
+    @property
+    def details(self) -> Document | None: ...
+
+class WriteConcernErrorResult:
+    @property
+    def code(self) -> int: ...
+    @property
+    def code_name(self) -> str: ...
+    @property
+    def message(self) -> str: ...
+    @property
+    def details(self) -> Document | None: ...
+
+class BulkWriteResult:
+    @property
+    def inserted_count(self) -> int: ...
+    @property
+    def matched_count(self) -> int: ...
+    @property
+    def modified_count(self) -> int: ...
+    @property
+    def deleted_count(self) -> int: ...
+    @property
+    def upserted_count(self) -> int: ...
+    @property
+    def inserted_ids(self) -> dict[int, BaseTypes]: ...
+    @property
+    def upserted_ids(self) -> dict[int, BaseTypes]: ...
+    @property
+    def write_errors(self) -> list[WriteErrorResult]: ...
+    @property
+    def write_concern_errors(self) -> list[WriteConcernErrorResult]: ...
+
 class DocumentsCursor(Generic[T]):
     def __aiter__(self) -> Self: ...
     async def __anext__(self) -> T: ...
+    async def next_batch(self, n: int) -> list[T]: ...
+    async def to_list(
+        self, max_length: int | None = None, max_bytes: int | None = None
+    ) -> list[T]: ...
     async def tolist(self) -> list[T]: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def alive(self) -> bool: ...
+    async def has_next(self) -> bool: ...
+    async def close(self) -> None: ...
+    async def __aenter__(self) -> Self: ...
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None: ...
 
 class IndexesCursor:
     def __aiter__(self) -> Self: ...
     async def __anext__(self) -> IndexModel | None: ...
     async def tolist(self) -> list[T]: ...
+    @property
+    def id(self) -> int: ...
+    @property
+    def alive(self) -> bool: ...
+    async def has_next(self) -> bool: ...
+    async def close(self) -> None: ...
+    async def __aenter__(self) -> Self: ...
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None: ...
+
+class ChangeNamespace:
+    def __repr__(self) -> str: ...
+    @property
+    def db(self) -> str: ...
+    @property
+    def coll(self) -> str | None: ...
+
+class TruncatedArray:
+    def __repr__(self) -> str: ...
+    @property
+    def field(self) -> str: ...
+    @property
+    def new_size(self) -> int: ...
+
+class UpdateDescription:
+    def __repr__(self) -> str: ...
+    @property
+    def updated_fields(self) -> Document: ...
+    @property
+    def removed_fields(self) -> list[str]: ...
+    @property
+    def truncated_arrays(self) -> list[TruncatedArray]: ...
+
+class ChangeEvent:
+    def __repr__(self) -> str: ...
+    @property
+    def resume_token(self) -> Document: ...
+    @property
+    def operation_type(self) -> str: ...
+    @property
+    def ns(self) -> ChangeNamespace | None: ...
+    @property
+    def to(self) -> ChangeNamespace | None: ...
+    @property
+    def document_key(self) -> Document | None: ...
+    @property
+    def update_description(self) -> UpdateDescription | None: ...
+    @property
+    def cluster_time(self) -> Timestamp | None: ...
+    @property
+    def wall_time(self) -> datetime | None: ...
+    @property
+    def full_document(self) -> Document | None: ...
+    @property
+    def full_document_before_change(self) -> Document | None: ...
+
+class ChangeStream:
+    def __aiter__(self) -> Self: ...
+    async def __anext__(self) -> ChangeEvent: ...
+    @property
+    def alive(self) -> bool: ...
+    @property
+    def resume_token(self) -> Document | None: ...
+    async def close(self) -> None: ...
+    async def __aenter__(self) -> Self: ...
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None: ...
+
+class CheckpointedChangeStream:
+    def __aiter__(self) -> Self: ...
+    async def __anext__(self) -> ChangeEvent: ...
+    @property
+    def alive(self) -> bool: ...
+    async def next_batch(self, max_events: int) -> list[ChangeEvent]: ...
+    async def checkpoint(self) -> None: ...
+    async def close(self) -> None: ...
+    async def __aenter__(self) -> Self: ...
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None: ...
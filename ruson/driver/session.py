@@ -1,6 +1,85 @@
+from typing import Awaitable, Callable, TypeVar
+
+from ..ruson import bindings
+from .types import Document, Timeout, Timestamp, TransactionOptions
+
+rust_client = bindings.client
+
+T = TypeVar("T")
+
+
 class Session:
     def __init__(self, binding_session):
         self.__binding_session = binding_session
+        self.__ended = False
 
     def _get_session(self):
+        if self.__ended:
+            raise ValueError("Cannot use a session that has ended")
         return self.__binding_session
+
+    @property
+    def in_transaction(self) -> bool:
+        return self.__binding_session.in_transaction
+
+    async def session_id(self) -> Document:
+        return await rust_client.session_id(self._get_session())
+
+    async def cluster_time(self) -> Document | None:
+        return await rust_client.cluster_time(self._get_session())
+
+    async def operation_time(self) -> Timestamp | None:
+        return await rust_client.operation_time(self._get_session())
+
+    async def advance_cluster_time(self, cluster_time: Document) -> None:
+        await rust_client.advance_cluster_time(self._get_session(), cluster_time)
+
+    async def advance_operation_time(self, operation_time: Timestamp) -> None:
+        await rust_client.advance_operation_time(self._get_session(), operation_time)
+
+    async def start_transaction(
+        self, options: TransactionOptions | None = None
+    ) -> None:
+        await rust_client.start_transaction(self._get_session(), options)
+
+    async def commit_transaction(self) -> None:
+        await rust_client.commit_transaction(self._get_session())
+
+    async def abort_transaction(self) -> None:
+        await rust_client.abort_transaction(self._get_session())
+
+    async def with_transaction(
+        self,
+        callback: Callable[["Session"], Awaitable[T]],
+        options: TransactionOptions | None = None,
+        timeout: int | Timeout | None = None,
+    ) -> T:
+        return await rust_client.with_transaction(
+            self._get_session(), lambda: callback(self), options, timeout
+        )
+
+    async def end_session(self) -> None:
+        if self.__ended:
+            return
+        try:
+            await rust_client.end_session(self.__binding_session)
+        finally:
+            self.__ended = True
+
+    async def __aenter__(self) -> "Session":
+        return self
+
+    async def __aexit__(self, exc_type, exc_value, traceback) -> None:
+        try:
+            if not self.in_transaction:
+                return
+            if exc_type is None:
+                await self.commit_transaction()
+                return
+            try:
+                await self.abort_transaction()
+            except Exception:
+                # The error raised in the block is more useful than the failed abort.
+                pass
+        finally:
+            await self.end_session()
@@ -1 +1,34 @@
-class Session: ...
+from types import TracebackType
+from typing import Awaitable, Callable, TypeVar
+
+from .types import Document, Timeout, Timestamp, TransactionOptions
+
+T = TypeVar("T")
+
+class Session:
+    @property
+    def in_transaction(self) -> bool: ...
+    async def session_id(self) -> Document: ...
+    async def cluster_time(self) -> Document | None: ...
+    async def operation_time(self) -> Timestamp | None: ...
+    async def advance_cluster_time(self, cluster_time: Document) -> None: ...
+    async def advance_operation_time(self, operation_time: Timestamp) -> None: ...
+    async def start_transaction(
+        self, options: TransactionOptions | None = None
+    ) -> None: ...
+    async def commit_transaction(self) -> None: ...
+    async def abort_transaction(self) -> None: ...
+    async def with_transaction(
+        self,
+        callback: Callable[[Session], Awaitable[T]],
+        options: TransactionOptions | None = None,
+        timeout: int | Timeout | None = None,
+    ) -> T: ...
+    async def end_session(self) -> None: ...
+    async def __aenter__(self) -> Session: ...
+    async def __aexit__(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_value: BaseException | None,
+        traceback: TracebackType | None,
+    ) -> None: ...
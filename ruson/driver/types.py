@@ -1,6 +1,8 @@
 from datetime import datetime
+from decimal import Decimal
 from enum import Enum
 from typing import Any, List, Literal, Mapping, Sequence, Union
+from uuid import UUID
 
 from pydantic import BaseModel
 from pydantic_core import core_schema
@@ -18,9 +20,33 @@ Decimal128 = bindings.types.Decimal128
 ObjectId = bindings.types.ObjectId
 Regex = bindings.types.Regex
 Timestamp = bindings.types.Timestamp
+DatetimeMS = bindings.types.DatetimeMS
 Undefined = bindings.types.Undefined
 IndexModel = bindings.types.IndexModel
 IndexOptions = bindings.types.IndexOptions
+ReturnDocument = bindings.types.ReturnDocument
+CursorType = bindings.types.CursorType
+ReadConcern = bindings.types.ReadConcern
+ReadPreference = bindings.types.ReadPreference
+Collation = bindings.types.Collation
+FindOptions = bindings.types.FindOptions
+WriteConcern = bindings.types.WriteConcern
+AggregateOptions = bindings.types.AggregateOptions
+InsertOptions = bindings.types.InsertOptions
+UpdateOptions = bindings.types.UpdateOptions
+DeleteOptions = bindings.types.DeleteOptions
+CountOptions = bindings.types.CountOptions
+ChangeStreamOptions = bindings.types.ChangeStreamOptions
+TransactionOptions = bindings.types.TransactionOptions
+SessionOptions = bindings.types.SessionOptions
+Timeout = bindings.types.Timeout
+CodecOptions = bindings.types.CodecOptions
+InsertOne = bindings.types.InsertOne
+UpdateOne = bindings.types.UpdateOne
+UpdateMany = bindings.types.UpdateMany
+ReplaceOne = bindings.types.ReplaceOne
+DeleteOne = bindings.types.DeleteOne
+DeleteMany = bindings.types.DeleteMany
 Document = bindings.types.Document
 DocumentIter = bindings.types.DocumentIter
 
@@ -39,9 +65,12 @@ BaseTypes = (
     | Binary
     | JavaScriptCode
     | Decimal128
+    | Decimal
+    | UUID
     | Regex
     | Timestamp
     | datetime
+    | DatetimeMS
     | Undefined
     | None
 )
@@ -53,6 +82,14 @@ CollectionTypes = (
 
 DocumentTypes = Document | Mapping[str, CollectionTypes | BaseTypes]
 
+UpdateModifications = Document | list[Document]
+
+UuidRepresentation = Literal[
+    "standard", "python_legacy", "java_legacy", "csharp_legacy", "unspecified"
+]
+
+WriteModel = InsertOne | UpdateOne | UpdateMany | ReplaceOne | DeleteOne | DeleteMany
+
 
 class Direction(Enum):
     ASCENDING = 1
@@ -87,6 +124,7 @@ UpdateOperators = Literal[
 ]
 
 Update = Mapping[UpdateOperators, Mapping[str, CollectionTypes | BaseTypes]]
+UpdatePipeline = list[Mapping[str, CollectionTypes | BaseTypes]]
 
 FilterTypes = (
     int | float | bool | str | Mapping[str, "FilterTypes"] | Sequence["FilterTypes"]
@@ -1,6 +1,9 @@
 from datetime import datetime
+from datetime import tzinfo as TzInfo
+from decimal import Decimal
 from enum import Enum
 from typing import Any, Callable, Iterable, List, Literal, Mapping, Self, Sequence
+from uuid import UUID
 
 from pydantic_core import core_schema
 
@@ -18,9 +21,12 @@ BaseTypes = (
     | Binary
     | JavaScriptCode
     | Decimal128
+    | Decimal
+    | UUID
     | Regex
     | Timestamp
     | datetime
+    | DatetimeMS
     | Undefined
     | None
 )
@@ -32,6 +38,14 @@ CollectionTypes = (
 
 DocumentTypes = Document | Mapping[str, CollectionTypes | BaseTypes]
 
+UpdateModifications = Document | list[Document]
+
+UuidRepresentation = Literal[
+    "standard", "python_legacy", "java_legacy", "csharp_legacy", "unspecified"
+]
+
+WriteModel = InsertOne | UpdateOne | UpdateMany | ReplaceOne | DeleteOne | DeleteMany
+
 class Direction(Enum):
     ASCENDING: 1
     DESCENDING: -1
@@ -61,6 +75,7 @@ UpdateOperators = Literal[
 ]
 
 Update = Mapping[UpdateOperators, Mapping[str, CollectionTypes | BaseTypes]]
+UpdatePipeline = list[Mapping[str, CollectionTypes | BaseTypes]]
 
 FilterTypes = (
     int | float | bool | str | Mapping[str, "FilterTypes"] | Sequence["FilterTypes"]
@@ -144,6 +159,14 @@ class Timestamp:
     @property
     def timestamp(self) -> int: ...
 
+class DatetimeMS:
+    def __init__(self, millis: int) -> None: ...
+    def __int__(self) -> int: ...
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    @property
+    def millis(self) -> int: ...
+
 class Regex:
     def __repr__(self) -> str: ...
     def __str__(self) -> str: ...
@@ -163,6 +186,13 @@ class BinarySubtype:
     def value(self) -> str: ...
 
 class Binary:
+    @staticmethod
+    def from_uuid(
+        value: UUID, uuid_representation: UuidRepresentation | None = None
+    ) -> Binary: ...
+    def as_uuid(
+        self, uuid_representation: UuidRepresentation | None = None
+    ) -> UUID: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     @property
@@ -196,6 +226,10 @@ class PydanticObjectId:
     def __get_pydantic_json_schema__(cls, _core_schema, handler): ...
 
 class Decimal128:
+    def __init__(self, value: Decimal | str) -> None: ...
+    @staticmethod
+    def from_decimal(value: Decimal) -> Decimal128: ...
+    def to_decimal(self) -> Decimal: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
     @property
@@ -291,3 +325,396 @@ class IndexModel:
     def options(self) -> IndexOptions | None: ...
     @options.setter
     def options(self, options: IndexOptions) -> None: ...
+
+class ReturnDocument:
+    Before: ReturnDocument
+    After: ReturnDocument
+
+class CursorType:
+    NonTailable: CursorType
+    Tailable: CursorType
+    TailableAwait: CursorType
+
+class ReadConcern:
+    def __init__(self, level: str) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def level(self) -> str: ...
+
+class ReadPreference:
+    def __init__(
+        self,
+        mode: Literal[
+            "primary", "primaryPreferred", "secondary", "secondaryPreferred", "nearest"
+        ],
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def mode(self) -> str: ...
+
+class Collation:
+    def __init__(
+        self,
+        locale: str,
+        strength: int | None = None,
+        case_level: bool | None = None,
+        case_first: Literal["upper", "lower", "off"] | None = None,
+        numeric_ordering: bool | None = None,
+        alternate: Literal["non-ignorable", "shifted"] | None = None,
+        max_variable: Literal["punct", "space"] | None = None,
+        normalization: bool | None = None,
+        backwards: bool | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def locale(self) -> str: ...
+    @property
+    def strength(self) -> int | None: ...
+    @property
+    def case_level(self) -> bool | None: ...
+    @property
+    def case_first(self) -> str | None: ...
+    @property
+    def numeric_ordering(self) -> bool | None: ...
+    @property
+    def alternate(self) -> str | None: ...
+    @property
+    def max_variable(self) -> str | None: ...
+    @property
+    def normalization(self) -> bool | None: ...
+    @property
+    def backwards(self) -> bool | None: ...
+
+class FindOptions:
+    def __init__(
+        self,
+        allow_disk_use: bool | None = None,
+        allow_partial_results: bool | None = None,
+        collation: Collation | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+        hint: str | Document | None = None,
+        max: Document | None = None,
+        min: Document | None = None,
+        no_cursor_timeout: bool | None = None,
+        read_concern: ReadConcern | None = None,
+        return_key: bool | None = None,
+        show_record_id: bool | None = None,
+        let_vars: Document | None = None,
+        cursor_type: CursorType | None = None,
+        max_await_time: int | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def allow_disk_use(self) -> bool | None: ...
+    @property
+    def allow_partial_results(self) -> bool | None: ...
+    @property
+    def collation(self) -> Collation | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+    @property
+    def hint(self) -> str | Document | None: ...
+    @property
+    def max(self) -> Document | None: ...
+    @property
+    def min(self) -> Document | None: ...
+    @property
+    def no_cursor_timeout(self) -> bool | None: ...
+    @property
+    def read_concern(self) -> ReadConcern | None: ...
+    @property
+    def return_key(self) -> bool | None: ...
+    @property
+    def show_record_id(self) -> bool | None: ...
+    @property
+    def let_vars(self) -> Document | None: ...
+    @property
+    def cursor_type(self) -> CursorType | None: ...
+    @property
+    def max_await_time(self) -> int | None: ...
+
+class WriteConcern:
+    def __init__(
+        self,
+        w: int | str | None = None,
+        w_timeout: int | None = None,
+        journal: bool | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def w(self) -> int | str | None: ...
+    @property
+    def w_timeout(self) -> int | None: ...
+    @property
+    def journal(self) -> bool | None: ...
+
+class AggregateOptions:
+    def __init__(
+        self,
+        allow_disk_use: bool | None = None,
+        bypass_document_validation: bool | None = None,
+        collation: Collation | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+        hint: str | Document | None = None,
+        read_concern: ReadConcern | None = None,
+        write_concern: WriteConcern | None = None,
+        let_vars: Document | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def allow_disk_use(self) -> bool | None: ...
+    @property
+    def bypass_document_validation(self) -> bool | None: ...
+    @property
+    def collation(self) -> Collation | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+    @property
+    def hint(self) -> str | Document | None: ...
+    @property
+    def read_concern(self) -> ReadConcern | None: ...
+    @property
+    def write_concern(self) -> WriteConcern | None: ...
+    @property
+    def let_vars(self) -> Document | None: ...
+
+class InsertOptions:
+    def __init__(
+        self,
+        ordered: bool | None = None,
+        bypass_document_validation: bool | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+        write_concern: WriteConcern | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def ordered(self) -> bool | None: ...
+    @property
+    def bypass_document_validation(self) -> bool | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+    @property
+    def write_concern(self) -> WriteConcern | None: ...
+
+class UpdateOptions:
+    def __init__(
+        self,
+        hint: str | Document | None = None,
+        collation: Collation | None = None,
+        let_vars: Document | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+        write_concern: WriteConcern | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def hint(self) -> str | Document | None: ...
+    @property
+    def collation(self) -> Collation | None: ...
+    @property
+    def let_vars(self) -> Document | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+    @property
+    def write_concern(self) -> WriteConcern | None: ...
+
+class DeleteOptions:
+    def __init__(
+        self,
+        hint: str | Document | None = None,
+        collation: Collation | None = None,
+        let_vars: Document | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+        write_concern: WriteConcern | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def hint(self) -> str | Document | None: ...
+    @property
+    def collation(self) -> Collation | None: ...
+    @property
+    def let_vars(self) -> Document | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+    @property
+    def write_concern(self) -> WriteConcern | None: ...
+
+class CountOptions:
+    def __init__(
+        self,
+        hint: str | Document | None = None,
+        collation: Collation | None = None,
+        read_concern: ReadConcern | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def hint(self) -> str | Document | None: ...
+    @property
+    def collation(self) -> Collation | None: ...
+    @property
+    def read_concern(self) -> ReadConcern | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+
+class ChangeStreamOptions:
+    def __init__(
+        self,
+        full_document: Literal["default", "updateLookup", "whenAvailable", "required"]
+        | None = None,
+        full_document_before_change: Literal["off", "whenAvailable", "required"]
+        | None = None,
+        resume_after: Document | None = None,
+        start_after: Document | None = None,
+        start_at_operation_time: Timestamp | None = None,
+        max_await_time: int | None = None,
+        batch_size: int | None = None,
+        collation: Collation | None = None,
+        read_concern: ReadConcern | None = None,
+        comment: BaseTypes | CollectionTypes | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def full_document(self) -> str | None: ...
+    @property
+    def full_document_before_change(self) -> str | None: ...
+    @property
+    def resume_after(self) -> Document | None: ...
+    @property
+    def start_after(self) -> Document | None: ...
+    @property
+    def start_at_operation_time(self) -> Timestamp | None: ...
+    @property
+    def max_await_time(self) -> int | None: ...
+    @property
+    def batch_size(self) -> int | None: ...
+    @property
+    def collation(self) -> Collation | None: ...
+    @property
+    def read_concern(self) -> ReadConcern | None: ...
+    @property
+    def comment(self) -> BaseTypes | CollectionTypes | None: ...
+
+class TransactionOptions:
+    def __init__(
+        self,
+        read_concern: ReadConcern | None = None,
+        write_concern: WriteConcern | None = None,
+        read_preference: ReadPreference | None = None,
+        max_commit_time: int | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def read_concern(self) -> ReadConcern | None: ...
+    @property
+    def write_concern(self) -> WriteConcern | None: ...
+    @property
+    def read_preference(self) -> ReadPreference | None: ...
+    @property
+    def max_commit_time(self) -> int | None: ...
+
+class SessionOptions:
+    def __init__(
+        self,
+        causal_consistency: bool | None = None,
+        snapshot: bool | None = None,
+        default_transaction_options: TransactionOptions | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def causal_consistency(self) -> bool | None: ...
+    @property
+    def snapshot(self) -> bool | None: ...
+    @property
+    def default_transaction_options(self) -> TransactionOptions | None: ...
+
+class Timeout:
+    def __init__(self, milliseconds: int) -> None: ...
+    @staticmethod
+    def unbounded() -> Timeout: ...
+    def __repr__(self) -> str: ...
+    @property
+    def milliseconds(self) -> int | None: ...
+
+class CodecOptions:
+    def __init__(
+        self,
+        tzinfo: TzInfo | None = None,
+        datetime_conversion: Literal["datetime", "clamp", "auto", "ms"] | None = None,
+        uuid_representation: UuidRepresentation | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def tzinfo(self) -> TzInfo | None: ...
+    @property
+    def datetime_conversion(self) -> Literal["datetime", "clamp", "auto", "ms"]: ...
+    @property
+    def uuid_representation(self) -> UuidRepresentation: ...
+
+class InsertOne:
+    def __init__(self, document: Document) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def document(self) -> Document: ...
+
+class UpdateOne:
+    def __init__(
+        self,
+        filter: Document,
+        update: UpdateModifications,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def filter(self) -> Document: ...
+    @property
+    def update(self) -> UpdateModifications: ...
+    @property
+    def upsert(self) -> bool | None: ...
+    @property
+    def array_filters(self) -> list[Document] | None: ...
+
+class UpdateMany:
+    def __init__(
+        self,
+        filter: Document,
+        update: UpdateModifications,
+        upsert: bool | None = None,
+        array_filters: list[Document] | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def filter(self) -> Document: ...
+    @property
+    def update(self) -> UpdateModifications: ...
+    @property
+    def upsert(self) -> bool | None: ...
+    @property
+    def array_filters(self) -> list[Document] | None: ...
+
+class ReplaceOne:
+    def __init__(
+        self,
+        filter: Document,
+        replacement: Document,
+        upsert: bool | None = None,
+    ) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def filter(self) -> Document: ...
+    @property
+    def replacement(self) -> Document: ...
+    @property
+    def upsert(self) -> bool | None: ...
+
+class DeleteOne:
+    def __init__(self, filter: Document) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def filter(self) -> Document: ...
+
+class DeleteMany:
+    def __init__(self, filter: Document) -> None: ...
+    def __repr__(self) -> str: ...
+    @property
+    def filter(self) -> Document: ...
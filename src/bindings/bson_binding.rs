@@ -1,16 +1,58 @@
-use std::{fmt::Display, num::ParseIntError, time::SystemTime};
+use std::fmt::Display;
 
-use mongodb::{self, bson};
+use mongodb::{
+    self,
+    bson::{self, uuid::UuidRepresentation},
+};
 
 use super::utils::PyNone;
 use pyo3::{
+    exceptions,
     prelude::*,
-    types::{timezone_utc, PyBool, PyBytes, PyDateTime, PyFloat, PyInt, PyList, PyString, PyType},
+    types::{
+        timezone_utc, PyBool, PyBytes, PyDateAccess, PyDateTime, PyDelta, PyFloat, PyInt, PyList,
+        PyString, PyTimeAccess, PyType, PyTzInfo,
+    },
 };
 
 use super::document_binding::Document;
+use super::options_binding::CodecOptions;
 
 const BINARY_SUBTYPE_USER_DEFINED: u8 = 0x80;
+/// The NaN values of decimal128 in its high bits, below the sign bit.
+const DECIMAL128_NAN: u128 = 0x7c << 120;
+const DECIMAL128_SNAN: u128 = 0x7e << 120;
+/// Marks the binaries that hold a `uuid.UUID` until they are sent to the server, where the codec
+/// options pick their representation. The marker is `BinarySubtype::UserDefined` of this value,
+/// which decoding never produces: binaries read with this subtype are `Reserved`.
+const NATIVE_UUID_SUBTYPE: u8 = 0x7f;
+const MILLIS_PER_DAY: i64 = 86_400_000;
+/// The range of `datetime`, from 0001-01-01 to 9999-12-31T23:59:59.999, in milliseconds since
+/// the Unix epoch.
+const DATETIME_MIN_MILLIS: i64 = -62_135_596_800_000;
+const DATETIME_MAX_MILLIS: i64 = 253_402_300_799_999;
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Appends `key` to the path of the value that contains it, as in "items.0.name".
+pub(crate) fn child_path(path: &str, key: impl Display) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// Describes where a value is for error messages, or nothing for a value outside a document.
+fn at_key(path: &str) -> String {
+    if path.is_empty() {
+        String::new()
+    } else {
+        format!(" at key '{}'", path)
+    }
+}
 
 #[pyclass(frozen, module = "ruson.types")]
 #[derive(Clone)]
@@ -131,6 +173,34 @@ impl Timestamp {
     }
 }
 
+/// A BSON datetime as milliseconds since the Unix epoch, for values outside the range of
+/// `datetime`.
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct DatetimeMS {
+    millis: i64,
+}
+
+#[pymethods]
+impl DatetimeMS {
+    #[new]
+    fn new(millis: i64) -> Self {
+        Self { millis }
+    }
+
+    fn __int__(&self) -> i64 {
+        self.millis
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ruson.types.DatetimeMS({})", self.millis)
+    }
+
+    fn __str__(&self) -> String {
+        bson::DateTime::from_millis(self.millis).to_string()
+    }
+}
+
 #[pyclass(get_all, set_all, module = "ruson.types")]
 #[derive(Clone)]
 pub struct Regex {
@@ -211,13 +281,36 @@ pub struct Binary {
 
 #[pymethods]
 impl Binary {
+    /// Encodes a `uuid.UUID` in the given representation, for collections whose codec options
+    /// leave the representation unspecified.
+    #[staticmethod]
+    fn from_uuid(value: &PyAny, uuid_representation: Option<String>) -> PyResult<Self> {
+        let representation = to_uuid_representation(uuid_representation)?;
+        let uuid = from_uuid(value)?;
+        Ok(bson::Binary::from_uuid_with_representation(uuid, representation).into())
+    }
+
+    /// Decodes the binary as a `uuid.UUID` in the given representation.
+    fn as_uuid(&self, py: Python<'_>, uuid_representation: Option<String>) -> PyResult<PyObject> {
+        let name = uuid_representation.unwrap_or_else(|| "standard".to_owned());
+        let representation = to_uuid_representation(Some(name.clone()))?;
+        let binary = bson::Binary {
+            subtype: from_subtype(self.subtype.id, self.subtype.value),
+            bytes: self.bytes.clone(),
+        };
+        match binary.to_uuid_with_representation(representation) {
+            Ok(uuid) => to_uuid(py, uuid),
+            Err(_) => Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                "{} binary of {} bytes is not a UUID in the {} representation",
+                self.subtype,
+                self.bytes.len(),
+                name
+            ))),
+        }
+    }
+
     fn __repr__(&self) -> String {
-        let byte_string = self
-            .bytes
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap();
+        let byte_string = to_hex(&self.bytes);
 
         format!(
             "ruson.types.Binary(subtype={}, bytes=\"{}\")",
@@ -226,12 +319,7 @@ impl Binary {
     }
 
     fn __str__(&self) -> String {
-        self.bytes
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap()
+        to_hex(&self.bytes)
     }
 
     #[getter]
@@ -241,17 +329,41 @@ impl Binary {
 
     #[getter]
     fn get_value(&self) -> PyObject {
-        let byte_string = self
-            .bytes
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap();
+        let byte_string = to_hex(&self.bytes);
 
         Python::with_gil(|py| byte_string.into_py(py))
     }
 }
 
+impl From<bson::Binary> for Binary {
+    fn from(v: bson::Binary) -> Self {
+        let binary_subtype = match &v.subtype {
+            bson::spec::BinarySubtype::Generic => BinarySubtype { id: 0, value: 0 },
+            bson::spec::BinarySubtype::Function => BinarySubtype { id: 1, value: 0 },
+            bson::spec::BinarySubtype::BinaryOld => BinarySubtype { id: 2, value: 0 },
+            bson::spec::BinarySubtype::UuidOld => BinarySubtype { id: 3, value: 0 },
+            bson::spec::BinarySubtype::Uuid => BinarySubtype { id: 4, value: 0 },
+            bson::spec::BinarySubtype::Md5 => BinarySubtype { id: 5, value: 0 },
+            bson::spec::BinarySubtype::Encrypted => BinarySubtype { id: 6, value: 0 },
+            bson::spec::BinarySubtype::Column => BinarySubtype { id: 7, value: 0 },
+            bson::spec::BinarySubtype::Reserved(v) => BinarySubtype { id: 9, value: *v },
+            bson::spec::BinarySubtype::UserDefined(v) => BinarySubtype {
+                id: BINARY_SUBTYPE_USER_DEFINED,
+                value: *v,
+            },
+            _ => BinarySubtype {
+                id: BINARY_SUBTYPE_USER_DEFINED,
+                value: v.subtype.into(),
+            },
+        };
+
+        Binary {
+            subtype: binary_subtype,
+            bytes: v.bytes,
+        }
+    }
+}
+
 #[pyclass(frozen, module = "ruson.types")]
 #[derive(Clone)]
 pub struct ObjectId {
@@ -268,24 +380,14 @@ impl ObjectId {
     }
 
     #[staticmethod]
-    fn from_str(value: String) -> Self {
-        if value.len() != 24 {
-            panic!("Value is not a valid ObjectId");
-        }
-
-        let s = value.as_str();
-        let id_vec: Result<Vec<u8>, ParseIntError> = (0..s.len())
-            .step_by(2)
-            .map(|i| u8::from_str_radix(&s[i..i + 2], 16))
-            .collect();
-
-        if id_vec.is_err() {
-            panic!("Value is not a valid ObjectId, invalid bytes found");
+    fn from_str(value: String) -> PyResult<Self> {
+        match bson::oid::ObjectId::parse_str(value.as_str()) {
+            Ok(id) => Ok(Self { id: id.bytes() }),
+            Err(_) => Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                "'{}' is not a valid ObjectId",
+                value
+            ))),
         }
-
-        let mut id: [u8; 12] = [0; 12];
-        id.copy_from_slice(id_vec.unwrap().as_slice());
-        Self { id }
     }
 
     #[classmethod]
@@ -295,35 +397,18 @@ impl ObjectId {
     }
 
     fn __repr__(&self) -> String {
-        let byte_string = self
-            .id
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap();
+        let byte_string = to_hex(&self.id);
 
         format!("ruson.types.ObjectId(\"{}\")", byte_string)
     }
 
     fn __str__(&self) -> String {
-        self.id
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap()
+        to_hex(&self.id)
     }
 
     #[getter]
     fn get_value(&self) -> PyObject {
-        let byte_string = self
-            .id
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap();
+        let byte_string = to_hex(&self.id);
 
         Python::with_gil(|py| byte_string.into_py(py))
     }
@@ -337,25 +422,40 @@ pub struct Decimal128 {
 
 #[pymethods]
 impl Decimal128 {
-    fn __repr__(&self) -> String {
-        let byte_string = self
-            .bytes
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap();
+    /// Accepts a `decimal.Decimal` or a string that `decimal.Decimal` can parse.
+    #[new]
+    fn new(value: &PyAny) -> PyResult<Self> {
+        let decimal = if value.is_instance_of::<PyString>() {
+            decimal_type(value.py())?.call1((value,))?
+        } else {
+            value
+        };
+        Self::from_decimal(decimal)
+    }
 
-        format!("ruson.types.Decimal128(\"{}\")", byte_string)
+    #[staticmethod]
+    fn from_decimal(value: &PyAny) -> PyResult<Self> {
+        if !value.is_instance(decimal_type(value.py())?)? {
+            return Err(PyErr::new::<exceptions::PyTypeError, _>(format!(
+                "Expected a decimal.Decimal, got {}",
+                value.get_type().name()?
+            )));
+        }
+        Ok(Self {
+            bytes: encode_decimal(value, "")?.bytes(),
+        })
+    }
+
+    fn to_decimal(&self, py: Python<'_>) -> PyResult<PyObject> {
+        decode_decimal(py, bson::Decimal128::from_bytes(self.bytes))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ruson.types.Decimal128(\"{}\")", self)
     }
 
     fn __str__(&self) -> String {
-        self.bytes
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap()
+        self.to_string()
     }
 
     #[getter]
@@ -365,32 +465,76 @@ impl Decimal128 {
 
     #[getter]
     fn get_value(&self) -> PyObject {
-        let byte_string = self
-            .bytes
-            .to_vec()
-            .iter()
-            .map(|b| format!("{:02x?}", b))
-            .reduce(|acc, v| acc + &v)
-            .unwrap();
+        let byte_string = to_hex(&self.bytes);
 
         Python::with_gil(|py| byte_string.into_py(py))
     }
 }
 
+impl Display for Decimal128 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        bson::Decimal128::from_bytes(self.bytes).fmt(f)
+    }
+}
+
+fn decimal_type(py: Python<'_>) -> PyResult<&PyAny> {
+    py.import("decimal")?.getattr("Decimal")
+}
+
+/// Encodes a `decimal.Decimal` as a decimal128. Values are never rounded: trailing zeros are moved
+/// into the exponent, or out of it, to fit, and values that still do not fit raise.
+fn encode_decimal(ob: &PyAny, path: &str) -> PyResult<bson::Decimal128> {
+    ob.str()?.to_str()?.parse().map_err(|e| {
+        PyErr::new::<exceptions::PyValueError, _>(format!(
+            "Decimal {}{} cannot be represented exactly as a Decimal128: {}",
+            ob,
+            at_key(path),
+            e
+        ))
+    })
+}
+
+/// Decodes a decimal128 to a `decimal.Decimal`, keeping the exponent so that "1.50" stays "1.50".
+fn decode_decimal(py: Python<'_>, decimal: bson::Decimal128) -> PyResult<PyObject> {
+    let bits = u128::from_le_bytes(decimal.bytes());
+    // The decimal128 string format writes every NaN as "NaN", without its sign or signalling bit.
+    let value = if bits & DECIMAL128_NAN == DECIMAL128_NAN {
+        let sign = if bits >> 127 == 1 { "-" } else { "" };
+        let signalling = if bits & DECIMAL128_SNAN == DECIMAL128_SNAN {
+            "s"
+        } else {
+            ""
+        };
+        format!("{}{}NaN", sign, signalling)
+    } else {
+        decimal.to_string()
+    };
+    Ok(decimal_type(py)?.call1((value,))?.into_py(py))
+}
+
 #[derive(Clone)]
 pub(crate) struct Bson(pub(crate) bson::Bson);
 
-impl IntoPy<PyObject> for Bson {
-    fn into_py(self, py: Python<'_>) -> PyObject {
-        match self.0 {
+impl Bson {
+    /// Converts the value to Python. `path` names the value in error messages, for values that
+    /// have no Python equivalent.
+    pub(crate) fn into_object(
+        self,
+        py: Python<'_>,
+        path: &str,
+        codec: &CodecOptions,
+    ) -> PyResult<PyObject> {
+        let value = match self.0 {
             bson::Bson::Double(v) => v.into_py(py),
             bson::Bson::String(v) => v.into_py(py),
-            bson::Bson::Array(v) => v
-                .into_iter()
-                .map(|v| Bson(v))
-                .collect::<Vec<Bson>>()
-                .into_py(py),
-            bson::Bson::Document(v) => Document(v).into_py(py),
+            bson::Bson::Array(v) => {
+                let mut values = Vec::with_capacity(v.len());
+                for (i, value) in v.into_iter().enumerate() {
+                    values.push(Bson(value).into_object(py, &child_path(path, i), codec)?);
+                }
+                values.into_py(py)
+            }
+            bson::Bson::Document(v) => Document(v, codec.clone()).into_py(py),
             bson::Bson::Boolean(v) => v.into_py(py),
             bson::Bson::Null => PyNone(py),
             bson::Bson::RegularExpression(v) => {
@@ -403,7 +547,7 @@ impl IntoPy<PyObject> for Bson {
             bson::Bson::JavaScriptCode(code) => JavaScriptCode { code }.into_py(py),
             bson::Bson::JavaScriptCodeWithScope(v) => {
                 let code = v.code;
-                let scope = Document(v.scope).into_py(py);
+                let scope = Document(v.scope, codec.clone()).into_py(py);
                 JavaScriptCodeWithScope { code, scope }.into_py(py)
             }
             bson::Bson::Int32(v) => v.into_py(py),
@@ -415,46 +559,12 @@ impl IntoPy<PyObject> for Bson {
                 };
                 value.into_py(py)
             }
-            bson::Bson::Binary(v) => {
-                let binary_subtype = match &v.subtype {
-                    bson::spec::BinarySubtype::Generic => BinarySubtype { id: 0, value: 0 },
-                    bson::spec::BinarySubtype::Function => BinarySubtype { id: 1, value: 0 },
-                    bson::spec::BinarySubtype::BinaryOld => BinarySubtype { id: 2, value: 0 },
-                    bson::spec::BinarySubtype::UuidOld => BinarySubtype { id: 3, value: 0 },
-                    bson::spec::BinarySubtype::Uuid => BinarySubtype { id: 4, value: 0 },
-                    bson::spec::BinarySubtype::Md5 => BinarySubtype { id: 5, value: 0 },
-                    bson::spec::BinarySubtype::Encrypted => BinarySubtype { id: 6, value: 0 },
-                    bson::spec::BinarySubtype::Column => BinarySubtype { id: 7, value: 0 },
-                    bson::spec::BinarySubtype::Reserved(v) => BinarySubtype { id: 9, value: *v },
-                    bson::spec::BinarySubtype::UserDefined(v) => BinarySubtype {
-                        id: BINARY_SUBTYPE_USER_DEFINED,
-                        value: *v,
-                    },
-                    _ => BinarySubtype {
-                        id: BINARY_SUBTYPE_USER_DEFINED,
-                        value: v.subtype.into(),
-                    },
-                };
-
-                let value = Binary {
-                    subtype: binary_subtype,
-                    bytes: v.bytes,
-                };
-                value.into_py(py)
-            }
+            bson::Bson::Binary(v) => match native_uuid(&v).or_else(|| decode_uuid(&v, codec)) {
+                Some(uuid) => to_uuid(py, uuid)?,
+                None => Binary::from(v).into_py(py),
+            },
             bson::Bson::ObjectId(v) => ObjectId { id: v.bytes() }.into_py(py),
-            bson::Bson::DateTime(v) => {
-                let duration = v
-                    .to_system_time()
-                    .duration_since(SystemTime::UNIX_EPOCH)
-                    .unwrap();
-                let timestamp = duration.as_secs_f64();
-                let value = PyDateTime::from_timestamp(py, timestamp, Some(timezone_utc(py)));
-                match value {
-                    Ok(v) => v.into_py(py),
-                    Err(e) => e.into_py(py),
-                }
-            }
+            bson::Bson::DateTime(v) => to_datetime(py, v, path, codec)?,
             bson::Bson::Decimal128(v) => {
                 let value = Decimal128 { bytes: v.bytes() };
                 value.into_py(py)
@@ -463,13 +573,37 @@ impl IntoPy<PyObject> for Bson {
             bson::Bson::Undefined => Undefined.into_py(py),
             bson::Bson::MaxKey => MaxKey.into_py(py),
             bson::Bson::MinKey => MinKey.into_py(py),
-            bson::Bson::DbPointer(_) => panic!("'DBPointer' type is not supported!"),
+            bson::Bson::DbPointer(_) => {
+                return Err(PyErr::new::<exceptions::PyTypeError, _>(format!(
+                    "DBPointer value{} is not supported",
+                    at_key(path)
+                )))
+            }
+        };
+        Ok(value)
+    }
+}
+
+/// For values that came from Python, such as ids and comments, which always convert back.
+/// Values read from the server go through `into_object`, which raises instead.
+impl IntoPy<PyObject> for Bson {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self.into_object(py, "", &CodecOptions::default()) {
+            Ok(v) => v,
+            Err(e) => e.into_py(py),
         }
     }
 }
 
 impl<'source> FromPyObject<'source> for Bson {
     fn extract(ob: &'source PyAny) -> PyResult<Self> {
+        Bson::extract_at(ob, "")
+    }
+}
+
+impl Bson {
+    /// Converts a Python value to BSON. `path` names the value in error messages.
+    pub(crate) fn extract_at(ob: &PyAny, path: &str) -> PyResult<Self> {
         if ob.is_instance_of::<Symbol>() {
             let value = ob.extract::<Symbol>()?;
             Ok(Bson(bson::Bson::Symbol(value.symbol)))
@@ -480,11 +614,10 @@ impl<'source> FromPyObject<'source> for Bson {
             let value = ob.extract::<String>()?;
             Ok(Bson(bson::Bson::String(value)))
         } else if ob.is_instance_of::<PyList>() {
-            let vector = ob.extract::<Vec<PyObject>>()?;
-            let mut bson_vector = Vec::with_capacity(vector.len());
-            for value in vector {
-                let inner = Python::with_gil(|py| Bson::extract(value.as_ref(py)))?;
-                bson_vector.push(inner.0);
+            let list = ob.downcast::<PyList>()?;
+            let mut bson_vector = Vec::with_capacity(list.len());
+            for (i, value) in list.iter().enumerate() {
+                bson_vector.push(Bson::extract_at(value, &child_path(path, i))?.0);
             }
             Ok(Bson(bson::Bson::Array(bson_vector)))
         } else if ob.is_instance_of::<Document>() {
@@ -510,8 +643,14 @@ impl<'source> FromPyObject<'source> for Bson {
             let value = bson::JavaScriptCodeWithScope { code, scope };
             Ok(Bson(bson::Bson::JavaScriptCodeWithScope(value)))
         } else if ob.is_instance_of::<PyInt>() {
-            let value = ob.extract::<i64>()?;
-            Ok(Bson(bson::Bson::Int64(value)))
+            match ob.extract::<i64>() {
+                Ok(value) => Ok(Bson(bson::Bson::Int64(value))),
+                Err(_) => Err(PyErr::new::<exceptions::PyOverflowError, _>(format!(
+                    "Integer {}{} does not fit in 64 bits",
+                    ob,
+                    at_key(path)
+                ))),
+            }
         } else if ob.is_instance_of::<Binary>() {
             let value = ob.extract::<Binary>()?;
             let value_subtype = value.subtype;
@@ -532,21 +671,12 @@ impl<'source> FromPyObject<'source> for Bson {
                 increment: value.increment,
             })))
         } else if ob.is_instance_of::<PyDateTime>() {
-            let year = ob.getattr("year").unwrap().extract::<i32>()?;
-            let month = ob.getattr("month").unwrap().extract::<u8>()?;
-            let day = ob.getattr("day").unwrap().extract::<u8>()?;
-            let hour = ob.getattr("hour").unwrap().extract::<u8>()?;
-            let minute = ob.getattr("minute").unwrap().extract::<u8>()?;
-            let second = ob.getattr("second").unwrap().extract::<u8>()?;
-
-            let builder = bson::DateTime::builder()
-                .year(year)
-                .month(month)
-                .day(day)
-                .hour(hour)
-                .minute(minute)
-                .second(second);
-            Ok(Bson(bson::Bson::DateTime(builder.build().unwrap())))
+            Ok(Bson(bson::Bson::DateTime(from_datetime(ob, path)?)))
+        } else if ob.is_instance_of::<DatetimeMS>() {
+            let value = ob.extract::<DatetimeMS>()?;
+            Ok(Bson(bson::Bson::DateTime(bson::DateTime::from_millis(
+                value.millis,
+            ))))
         } else if ob.is_instance_of::<Undefined>() {
             Ok(Bson(bson::Bson::Undefined))
         } else if ob.is_instance_of::<MaxKey>() {
@@ -557,9 +687,236 @@ impl<'source> FromPyObject<'source> for Bson {
             let value = ob.extract::<Decimal128>()?;
             let decimal = bson::Decimal128::from_bytes(value.bytes);
             Ok(Bson(bson::Bson::Decimal128(decimal)))
+        } else if ob.is_instance(uuid_type(ob.py())?)? {
+            let bytes = from_uuid(ob)?.bytes().to_vec();
+            Ok(Bson(bson::Bson::Binary(bson::Binary {
+                subtype: bson::spec::BinarySubtype::UserDefined(NATIVE_UUID_SUBTYPE),
+                bytes,
+            })))
+        } else if ob.is_instance(decimal_type(ob.py())?)? {
+            Ok(Bson(bson::Bson::Decimal128(encode_decimal(ob, path)?)))
         } else {
-            panic!("Type {} is not convertible to BSON", ob.get_type().name()?);
+            Err(PyErr::new::<exceptions::PyTypeError, _>(format!(
+                "Value of type {}{} is not convertible to BSON",
+                ob.get_type().name()?,
+                at_key(path)
+            )))
+        }
+    }
+}
+
+/// Converts a datetime to BSON, which stores milliseconds since the Unix epoch in UTC. Aware
+/// datetimes are converted to UTC, naive datetimes are taken to be in UTC already, and
+/// microseconds are truncated to milliseconds.
+fn from_datetime(ob: &PyAny, path: &str) -> PyResult<bson::DateTime> {
+    let py = ob.py();
+    let datetime = if ob.call_method0("utcoffset")?.is_none() {
+        ob.downcast::<PyDateTime>()?
+    } else {
+        match ob.call_method1("astimezone", (timezone_utc(py),)) {
+            Ok(v) => v.downcast::<PyDateTime>()?,
+            Err(_) => {
+                return Err(PyErr::new::<exceptions::PyOverflowError, _>(format!(
+                    "Datetime {}{} is out of range in UTC",
+                    ob,
+                    at_key(path)
+                )))
+            }
+        }
+    };
+    let builder = bson::DateTime::builder()
+        .year(datetime.get_year())
+        .month(datetime.get_month())
+        .day(datetime.get_day())
+        .hour(datetime.get_hour())
+        .minute(datetime.get_minute())
+        .second(datetime.get_second())
+        .millisecond((datetime.get_microsecond() / 1000) as u16);
+    builder.build().map_err(|e| {
+        PyErr::new::<exceptions::PyValueError, _>(format!(
+            "Datetime {}{} is not a valid BSON datetime: {}",
+            ob,
+            at_key(path),
+            e
+        ))
+    })
+}
+
+/// Converts a BSON datetime to Python as `codec` asks.
+fn to_datetime(
+    py: Python<'_>,
+    value: bson::DateTime,
+    path: &str,
+    codec: &CodecOptions,
+) -> PyResult<PyObject> {
+    let mut millis = value.timestamp_millis();
+    let in_range = (DATETIME_MIN_MILLIS..=DATETIME_MAX_MILLIS).contains(&millis);
+    match codec.datetime_conversion.as_str() {
+        "ms" => return Ok(DatetimeMS { millis }.into_py(py)),
+        "auto" if !in_range => return Ok(DatetimeMS { millis }.into_py(py)),
+        "clamp" => millis = millis.clamp(DATETIME_MIN_MILLIS, DATETIME_MAX_MILLIS),
+        _ if !in_range => {
+            return Err(PyErr::new::<exceptions::PyOverflowError, _>(format!(
+                "Datetime {}{} is out of the range of Python datetimes",
+                value,
+                at_key(path)
+            )))
+        }
+        _ => (),
+    }
+
+    // Adding a timedelta to the epoch keeps every millisecond, which a float timestamp does not.
+    let epoch = PyDateTime::new(py, 1970, 1, 1, 0, 0, 0, 0, Some(timezone_utc(py)))?;
+    let delta = PyDelta::new(
+        py,
+        millis.div_euclid(MILLIS_PER_DAY) as i32,
+        (millis.rem_euclid(MILLIS_PER_DAY) / 1000) as i32,
+        (millis.rem_euclid(1000) * 1000) as i32,
+        false,
+    )?;
+    let datetime = epoch.call_method1("__add__", (delta,))?;
+    let tz = match &codec.tzinfo {
+        Some(tz) => tz.as_ref(py),
+        None => return Ok(datetime.into_py(py)),
+    };
+    match datetime.call_method1("astimezone", (tz,)) {
+        Ok(datetime) => Ok(datetime.into_py(py)),
+        // A datetime near the limits can leave the range of `datetime` in a time zone far from
+        // UTC, so clamping also has to happen in that time zone.
+        Err(e)
+            if codec.datetime_conversion == "clamp"
+                && e.is_instance_of::<exceptions::PyOverflowError>(py) =>
+        {
+            let tz = tz.downcast::<PyTzInfo>()?;
+            let datetime = if millis < 0 {
+                PyDateTime::new(py, 1, 1, 1, 0, 0, 0, 0, Some(tz))?
+            } else {
+                PyDateTime::new(py, 9999, 12, 31, 23, 59, 59, 999_999, Some(tz))?
+            };
+            Ok(datetime.into_py(py))
+        }
+        Err(e) => Err(e),
+    }
+}
+
+fn uuid_type(py: Python<'_>) -> PyResult<&PyAny> {
+    py.import("uuid")?.getattr("UUID")
+}
+
+fn from_uuid(ob: &PyAny) -> PyResult<bson::Uuid> {
+    if !ob.is_instance(uuid_type(ob.py())?)? {
+        return Err(PyErr::new::<exceptions::PyTypeError, _>(format!(
+            "Expected a uuid.UUID, got {}",
+            ob.get_type().name()?
+        )));
+    }
+    let bytes: [u8; 16] = ob.getattr("bytes")?.extract()?;
+    Ok(bson::Uuid::from_bytes(bytes))
+}
+
+fn to_uuid(py: Python<'_>, uuid: bson::Uuid) -> PyResult<PyObject> {
+    Ok(uuid_type(py)?.call1((uuid.to_string(),))?.into_py(py))
+}
+
+fn to_uuid_representation(value: Option<String>) -> PyResult<UuidRepresentation> {
+    match CodecOptions::parse_uuid_representation(value.as_deref().unwrap_or("standard"))? {
+        Some(representation) => Ok(representation),
+        None => Err(PyErr::new::<exceptions::PyValueError, _>(
+            "A UUID representation is required",
+        )),
+    }
+}
+
+/// Decodes the binaries that hold UUIDs in the codec's representation: subtype 4 always holds a
+/// standard UUID, while subtype 3 holds one in the byte order of a legacy representation.
+/// Nothing is decoded when the representation is unspecified.
+fn decode_uuid(binary: &bson::Binary, codec: &CodecOptions) -> Option<bson::Uuid> {
+    let representation = match (binary.subtype, codec.to_uuid_representation()?) {
+        (bson::spec::BinarySubtype::Uuid, _) => UuidRepresentation::Standard,
+        (bson::spec::BinarySubtype::UuidOld, UuidRepresentation::Standard) => return None,
+        (bson::spec::BinarySubtype::UuidOld, representation) => representation,
+        _ => return None,
+    };
+    binary.to_uuid_with_representation(representation).ok()
+}
+
+/// The `uuid.UUID` held by a binary converted from Python, which has not been sent yet.
+fn native_uuid(binary: &bson::Binary) -> Option<bson::Uuid> {
+    match binary.subtype {
+        bson::spec::BinarySubtype::UserDefined(NATIVE_UUID_SUBTYPE) => Some(
+            bson::Uuid::from_bytes(binary.bytes.as_slice().try_into().ok()?),
+        ),
+        _ => None,
+    }
+}
+
+/// Values on their way to the server, whose `uuid.UUID` values are encoded in the UUID
+/// representation of the collection. Binaries, including the ones built with
+/// `Binary.from_uuid`, are sent as they are.
+pub(crate) trait EncodeUuids {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()>;
+}
+
+impl EncodeUuids for bson::Bson {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        match self {
+            bson::Bson::Binary(binary) => {
+                if let Some(uuid) = native_uuid(binary) {
+                    let representation = representation.ok_or_else(|| {
+                        PyErr::new::<exceptions::PyValueError, _>(
+                            "Cannot store a uuid.UUID when the UUID representation is \
+                             unspecified. Choose a uuid_representation in the codec options, or \
+                             use Binary.from_uuid",
+                        )
+                    })?;
+                    *binary = bson::Binary::from_uuid_with_representation(uuid, representation);
+                }
+                Ok(())
+            }
+            bson::Bson::Array(values) => values.encode_uuids(representation),
+            bson::Bson::Document(document) => document.encode_uuids(representation),
+            bson::Bson::JavaScriptCodeWithScope(code) => code.scope.encode_uuids(representation),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl EncodeUuids for bson::Document {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        for (_, value) in self.iter_mut() {
+            value.encode_uuids(representation)?;
+        }
+        Ok(())
+    }
+}
+
+impl EncodeUuids for Document {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.0.encode_uuids(representation)
+    }
+}
+
+impl EncodeUuids for Bson {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.0.encode_uuids(representation)
+    }
+}
+
+impl<T: EncodeUuids> EncodeUuids for Option<T> {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        match self {
+            Some(value) => value.encode_uuids(representation),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<T: EncodeUuids> EncodeUuids for Vec<T> {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        for value in self.iter_mut() {
+            value.encode_uuids(representation)?;
         }
+        Ok(())
     }
 }
 
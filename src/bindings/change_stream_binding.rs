@@ -0,0 +1,447 @@
+use std::{sync::Arc, time::Duration};
+
+use mongodb::{bson, change_stream::event::ResumeToken};
+use pyo3::{exceptions, prelude::*};
+use tokio::sync::Mutex;
+
+use crate::interface;
+
+use super::bson_binding::Bson;
+use super::collection_binding::Collection;
+use super::document_binding::Document;
+use super::options_binding::{ChangeStreamOptions, CodecOptions};
+use super::utils::to_py_err;
+
+/// How many times in a row a checkpointed stream reopens after resumable errors when no
+/// `max_retries` is given.
+const MAX_RETRIES: u32 = 10;
+
+/// Converts a resume token to the document the server sent, e.g. `{"_data": "..."}`.
+pub(crate) fn resume_token_to_document(token: &ResumeToken) -> PyResult<Document> {
+    match bson::to_bson(token) {
+        Ok(bson::Bson::Document(d)) => Ok(Document::from(d)),
+        Ok(_) => Err(PyErr::new::<exceptions::PyValueError, _>(
+            "Resume token is not a document",
+        )),
+        Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct ChangeNamespace {
+    /// The database the change happened in.
+    pub db: String,
+
+    /// The collection the change happened in. Missing for database-wide events.
+    pub coll: Option<String>,
+}
+
+impl From<mongodb::change_stream::event::ChangeNamespace> for ChangeNamespace {
+    fn from(value: mongodb::change_stream::event::ChangeNamespace) -> Self {
+        Self {
+            db: value.db,
+            coll: value.coll,
+        }
+    }
+}
+
+#[pymethods]
+impl ChangeNamespace {
+    fn __repr__(&self) -> String {
+        match &self.coll {
+            Some(coll) => format!("ruson.types.ChangeNamespace(\"{}.{}\")", self.db, coll),
+            None => format!("ruson.types.ChangeNamespace(\"{}\")", self.db),
+        }
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct TruncatedArray {
+    /// The path of the truncated array.
+    pub field: String,
+
+    /// The length of the array after the update.
+    pub new_size: i32,
+}
+
+#[pymethods]
+impl TruncatedArray {
+    fn __repr__(&self) -> String {
+        format!(
+            "ruson.types.TruncatedArray(field=\"{}\", new_size={})",
+            self.field, self.new_size
+        )
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct UpdateDescription {
+    /// The fields set by the update and their new values.
+    pub updated_fields: Document,
+
+    /// The fields removed by the update.
+    pub removed_fields: Vec<String>,
+
+    /// The arrays shortened by the update.
+    pub truncated_arrays: Vec<TruncatedArray>,
+}
+
+impl From<mongodb::change_stream::event::UpdateDescription> for UpdateDescription {
+    fn from(value: mongodb::change_stream::event::UpdateDescription) -> Self {
+        Self {
+            updated_fields: Document::from(value.updated_fields),
+            removed_fields: value.removed_fields,
+            truncated_arrays: value
+                .truncated_arrays
+                .unwrap_or_default()
+                .into_iter()
+                .map(|a| TruncatedArray {
+                    field: a.field,
+                    new_size: a.new_size,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl UpdateDescription {
+    fn __repr__(&self) -> String {
+        "ruson.types.UpdateDescription(...)".to_owned()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct ChangeEvent {
+    /// The token to resume the stream after this event.
+    pub resume_token: Document,
+
+    /// The kind of change, e.g. "insert", "update", "replace", "delete", "drop", "rename",
+    /// "dropDatabase" or "invalidate".
+    pub operation_type: String,
+
+    /// The namespace the change happened in.
+    pub ns: Option<ChangeNamespace>,
+
+    /// The new namespace of a renamed collection.
+    pub to: Option<ChangeNamespace>,
+
+    /// The `_id`, and shard key if any, of the changed document.
+    pub document_key: Option<Document>,
+
+    /// The fields changed by an update.
+    pub update_description: Option<UpdateDescription>,
+
+    /// The cluster time of the change, as a `Timestamp`.
+    pub(crate) cluster_time: Option<Bson>,
+
+    /// The server's wall clock time of the change.
+    pub(crate) wall_time: Option<Bson>,
+
+    /// The document after the change, as selected by the `full_document` option.
+    pub full_document: Option<Document>,
+
+    /// The document before the change, as selected by the `full_document_before_change` option.
+    pub full_document_before_change: Option<Document>,
+}
+
+impl ChangeEvent {
+    pub(crate) fn from_event(
+        event: interface::ChangeEvent,
+        codec: &CodecOptions,
+    ) -> PyResult<Self> {
+        let operation_type = match bson::to_bson(&event.operation_type) {
+            Ok(bson::Bson::String(s)) => s,
+            Ok(v) => v.to_string(),
+            Err(e) => return Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+        };
+        Ok(Self {
+            resume_token: resume_token_to_document(&event.id)?,
+            operation_type,
+            ns: event.ns.map(Into::into),
+            to: event.to.map(Into::into),
+            document_key: event.document_key.map(|d| Document(d, codec.clone())),
+            update_description: event.update_description.map(Into::into),
+            cluster_time: event.cluster_time.map(|ts| Bson(bson::Bson::Timestamp(ts))),
+            wall_time: event.wall_time.map(|dt| Bson(bson::Bson::DateTime(dt))),
+            full_document: event.full_document.map(|d| Document(d, codec.clone())),
+            full_document_before_change: event
+                .full_document_before_change
+                .map(|d| Document(d, codec.clone())),
+        })
+    }
+}
+
+#[pymethods]
+impl ChangeEvent {
+    fn __repr__(&self) -> String {
+        format!(
+            "ruson.types.ChangeEvent(operation_type=\"{}\", ...)",
+            self.operation_type
+        )
+    }
+}
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct ChangeStream {
+    stream: Arc<Mutex<interface::ChangeStreamIterator>>,
+    // Kept outside the stream's lock, which is held while waiting for events.
+    resume_token: Arc<std::sync::Mutex<Option<ResumeToken>>>,
+    closer: interface::Closer,
+    codec: CodecOptions,
+}
+
+impl ChangeStream {
+    pub(crate) fn new(stream: interface::ChangeStreamIterator, codec: CodecOptions) -> Self {
+        Self {
+            resume_token: Arc::new(std::sync::Mutex::new(stream.resume_token())),
+            closer: stream.closer(),
+            stream: Arc::new(Mutex::new(stream)),
+            codec,
+        }
+    }
+}
+
+#[pymethods]
+impl ChangeStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Whether the stream may still return events. It is false once the stream is closed or
+    /// invalidated.
+    #[getter]
+    fn alive(&self) -> bool {
+        // A locked stream is waiting for events, so it is still alive.
+        !self.closer.is_closed() && self.stream.try_lock().map_or(true, |s| s.alive())
+    }
+
+    /// The token to resume the stream after the last event returned, or `None` when the server
+    /// has not sent one yet.
+    #[getter]
+    fn resume_token(&self) -> PyResult<Option<Document>> {
+        let token = self.resume_token.lock().unwrap().clone();
+        match token {
+            Some(t) => Ok(Some(resume_token_to_document(&t)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Kills the stream's cursor on the server right away instead of when the stream is garbage
+    /// collected. An iteration waiting for events stops first.
+    fn close<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        self.closer.close();
+        let stream = self.stream.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+            stream.lock().await.close();
+            Ok(())
+        })
+    }
+
+    fn __aenter__<'a>(slf: PyRef<'a, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let stream: PyObject = slf.into_py(py);
+        pyo3_asyncio::tokio::future_into_py::<_, PyObject>(py, async move { Ok(stream) })
+    }
+
+    fn __aexit__<'a>(
+        &self,
+        py: Python<'a>,
+        _exc_type: &PyAny,
+        _exc_value: &PyAny,
+        _traceback: &PyAny,
+    ) -> PyResult<&'a PyAny> {
+        self.close(py)
+    }
+
+    /// Waits for the next event. Raises `StopAsyncIteration` once the stream is closed or
+    /// invalidated.
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let stream = self.stream.clone();
+        let resume_token = self.resume_token.clone();
+        let codec = self.codec.clone();
+        let future = pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut inner_stream = stream.lock().await;
+            let result = inner_stream.next().await;
+            // A closed stream has no token, but the last one is still valid to resume from.
+            if let Some(token) = inner_stream.resume_token() {
+                *resume_token.lock().unwrap() = Some(token);
+            }
+            match result {
+                Ok(Some(event)) => ChangeEvent::from_event(event, &codec),
+                Ok(None) => Err(PyErr::new::<exceptions::PyStopAsyncIteration, _>(())),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })?;
+        Ok(Some(future.into()))
+    }
+}
+
+#[pyclass(frozen)]
+#[derive(Clone)]
+pub struct CheckpointedChangeStream(
+    pub(crate) Arc<Mutex<interface::CheckpointedChangeStream>>,
+    pub(crate) CodecOptions,
+    interface::Closer,
+);
+
+impl CheckpointedChangeStream {
+    pub(crate) fn new(stream: interface::CheckpointedChangeStream, codec: CodecOptions) -> Self {
+        let closer = stream.closer();
+        CheckpointedChangeStream(Arc::new(Mutex::new(stream)), codec, closer)
+    }
+}
+
+/// Opens a change stream on `target` that saves its progress as `consumer_name` in
+/// `checkpoints`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn watch_with_checkpoints<'a>(
+    py: Python<'a>,
+    target: interface::WatchTarget,
+    codec: CodecOptions,
+    checkpoints: Collection,
+    consumer_name: String,
+    mut pipeline: Option<Vec<Document>>,
+    mut options: Option<ChangeStreamOptions>,
+    checkpoint_every: Option<usize>,
+    checkpoint_interval: Option<u64>,
+    max_retries: Option<u32>,
+) -> PyResult<&'a PyAny> {
+    codec.encode(&mut pipeline)?;
+    codec.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
+    let checkpoints = interface::Checkpoints {
+        collection: checkpoints.0,
+        name: consumer_name,
+        every: checkpoint_every,
+        interval: checkpoint_interval.map(Duration::from_millis),
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, CheckpointedChangeStream>(py, async move {
+        let pipeline = pipeline.unwrap_or_default().into_iter().map(|d| d.0);
+        let result = interface::CheckpointedChangeStream::start(
+            target,
+            pipeline.collect(),
+            options,
+            checkpoints,
+            max_retries.unwrap_or(MAX_RETRIES),
+        )
+        .await;
+        match result {
+            Ok(v) => Ok(CheckpointedChangeStream::new(v, codec)),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pymethods]
+impl CheckpointedChangeStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// Whether the stream may still return events. It is false once the stream is closed or
+    /// invalidated.
+    #[getter]
+    fn alive(&self) -> bool {
+        // A locked stream is waiting for events, so it is still alive.
+        !self.2.is_closed() && self.0.try_lock().map_or(true, |s| s.alive())
+    }
+
+    /// Waits for the next events and returns up to `max_events` of them. Marks the events
+    /// returned before as processed. The list is empty once the stream is closed or
+    /// invalidated.
+    fn next_batch<'a>(&self, py: Python<'a>, max_events: usize) -> PyResult<&'a PyAny> {
+        let stream = self.0.clone();
+        let codec = self.1.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, Vec<ChangeEvent>>(py, async move {
+            let result = stream.lock().await.next_batch(max_events).await;
+            match result {
+                Ok(v) => v
+                    .into_iter()
+                    .map(|event| ChangeEvent::from_event(event, &codec))
+                    .collect(),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })
+    }
+
+    /// Marks every event returned so far as processed and saves a checkpoint right away.
+    fn checkpoint<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let stream = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+            let result = stream.lock().await.checkpoint().await;
+            match result {
+                Ok(_) => Ok(()),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })
+    }
+
+    /// Kills the stream's cursor on the server without saving a checkpoint. An iteration waiting
+    /// for events stops first.
+    fn close<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        self.2.close();
+        let stream = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+            stream.lock().await.close();
+            Ok(())
+        })
+    }
+
+    fn __aenter__<'a>(slf: PyRef<'a, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let stream: PyObject = slf.into_py(py);
+        pyo3_asyncio::tokio::future_into_py::<_, PyObject>(py, async move { Ok(stream) })
+    }
+
+    /// Saves a checkpoint and closes the stream. When the block raised, the events returned
+    /// since the last checkpoint are not marked as processed.
+    fn __aexit__<'a>(
+        &self,
+        py: Python<'a>,
+        exc_type: &PyAny,
+        _exc_value: &PyAny,
+        _traceback: &PyAny,
+    ) -> PyResult<&'a PyAny> {
+        self.2.close();
+        let stream = self.0.clone();
+        let failed = !exc_type.is_none();
+        pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+            let mut inner_stream = stream.lock().await;
+            let result = if failed {
+                Ok(())
+            } else {
+                inner_stream.checkpoint().await
+            };
+            inner_stream.close();
+            match result {
+                Ok(_) => Ok(()),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })
+    }
+
+    /// Waits for the next event and marks the one before it as processed. Raises
+    /// `StopAsyncIteration` once the stream is closed or invalidated.
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let stream = self.0.clone();
+        let codec = self.1.clone();
+        let future = pyo3_asyncio::tokio::future_into_py(py, async move {
+            let result = stream.lock().await.next_batch(1).await;
+            match result {
+                Ok(v) => match v.into_iter().next() {
+                    Some(event) => ChangeEvent::from_event(event, &codec),
+                    None => Err(PyErr::new::<exceptions::PyStopAsyncIteration, _>(())),
+                },
+                Err(e) => Err(to_py_err(e)),
+            }
+        })?;
+        Ok(Some(future.into()))
+    }
+}
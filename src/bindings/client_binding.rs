@@ -1,43 +1,130 @@
-use std::sync::Arc;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
 
-use mongodb;
+use mongodb::{
+    self,
+    error::{
+        ErrorKind, WriteFailure, TRANSIENT_TRANSACTION_ERROR, UNKNOWN_TRANSACTION_COMMIT_RESULT,
+    },
+};
 use pyo3::{exceptions, prelude::*};
-use tokio::sync::Mutex;
+use tokio::{sync::Mutex, time::Instant};
 
+use super::bson_binding::Bson;
+use super::change_stream_binding::{self, ChangeStream};
+use super::collection_binding::Collection;
 use super::database_biding::Database;
+use super::document_binding::Document;
+use super::options_binding::{
+    ChangeStreamOptions, CodecOptions, SessionOptions, TimeoutValue, TransactionOptions,
+};
+use super::utils::to_py_err;
 use crate::interface;
 
+/// How long `with_transaction` keeps retrying when no timeout is given.
+const WITH_TRANSACTION_TIMEOUT: interface::Timeout = interface::Timeout::Milliseconds(120_000);
+
 #[pyclass(frozen)]
-#[repr(transparent)]
 #[derive(Clone)]
-pub struct Client(pub(crate) mongodb::Client);
+pub struct Client(
+    pub(crate) mongodb::Client,
+    pub(crate) interface::Timeout,
+    pub(crate) CodecOptions,
+);
 
+/// A session, until it is ended, and whether a transaction is in progress on it, which the
+/// driver does not expose.
 #[pyclass]
-#[repr(transparent)]
 #[derive(Clone)]
-pub struct ClientSession(pub(crate) Arc<Mutex<mongodb::ClientSession>>);
+pub struct ClientSession(
+    Arc<std::sync::Mutex<Option<Arc<Mutex<mongodb::ClientSession>>>>>,
+    Arc<AtomicBool>,
+);
+
+impl ClientSession {
+    fn new(session: mongodb::ClientSession) -> Self {
+        Self(
+            Arc::new(std::sync::Mutex::new(Some(Arc::new(Mutex::new(session))))),
+            Arc::new(AtomicBool::new(false)),
+        )
+    }
+
+    pub(crate) fn session(&self) -> PyResult<Arc<Mutex<mongodb::ClientSession>>> {
+        match self.0.lock().unwrap().as_ref() {
+            Some(session) => Ok(session.clone()),
+            None => Err(PyErr::new::<exceptions::PyValueError, _>(
+                "Cannot use a session that has ended",
+            )),
+        }
+    }
+
+    pub(crate) fn in_transaction(&self) -> bool {
+        self.1.load(Ordering::SeqCst)
+    }
+
+    /// Records whether a transaction is in progress. The driver leaves the transaction state as
+    /// soon as a commit or an abort is attempted, whether or not it succeeds.
+    fn set_in_transaction(&self, value: bool) {
+        self.1.store(value, Ordering::SeqCst);
+    }
+}
+
+#[pymethods]
+impl ClientSession {
+    /// Whether a transaction is in progress, including one run by `with_transaction`.
+    #[getter]
+    fn get_in_transaction(&self) -> bool {
+        self.in_transaction()
+    }
+}
 
 #[pyfunction]
-pub fn create_client(py: Python, db_uri: String) -> PyResult<&PyAny> {
+pub fn create_client<'a>(
+    py: Python<'a>,
+    db_uri: String,
+    timeout: Option<TimeoutValue>,
+    codec_options: Option<CodecOptions>,
+) -> PyResult<&'a PyAny> {
+    let timeout = timeout.map_or(interface::Timeout::DEFAULT, Into::into);
+    let codec_options = codec_options.unwrap_or_default();
     pyo3_asyncio::tokio::future_into_py::<_, Client>(py, async move {
         let result = interface::create_client(db_uri.as_str()).await;
         match result {
-            Ok(c) => Ok(Client(c)),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(c) => Ok(Client(c, timeout, codec_options)),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
-pub fn database(client: &Client, database_name: String) -> Database {
-    Database(client.0.database(database_name.as_str()))
+pub fn database(
+    client: &Client,
+    database_name: String,
+    timeout: Option<TimeoutValue>,
+    codec_options: Option<CodecOptions>,
+) -> Database {
+    Database(
+        client.0.database(database_name.as_str()),
+        timeout.map_or(client.1, Into::into),
+        codec_options.unwrap_or_else(|| client.2.clone()),
+    )
 }
 
 #[pyfunction]
-pub fn default_database(client: &Client) -> PyResult<Database> {
+pub fn default_database(
+    client: &Client,
+    timeout: Option<TimeoutValue>,
+    codec_options: Option<CodecOptions>,
+) -> PyResult<Database> {
     let db = client.0.default_database();
     match db {
-        Some(db) => Ok(Database(db)),
+        Some(db) => Ok(Database(
+            db,
+            timeout.map_or(client.1, Into::into),
+            codec_options.unwrap_or_else(|| client.2.clone()),
+        )),
         None => Err(PyErr::new::<exceptions::PyValueError, _>(
             "No default database configured. Check your URI.",
         )),
@@ -45,27 +132,330 @@ pub fn default_database(client: &Client) -> PyResult<Database> {
 }
 
 #[pyfunction]
-pub fn list_database_names<'a>(py: Python<'a>, client: &Client) -> PyResult<&'a PyAny> {
+pub fn list_database_names<'a>(
+    py: Python<'a>,
+    client: &Client,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
     let client = client.0.clone();
     pyo3_asyncio::tokio::future_into_py::<_, Vec<String>>(py, async move {
-        let future = client.list_database_names(None, None);
-        let database_names = future.await;
+        let database_names = match session {
+            Some(s) => {
+                let s = s.session()?;
+                let mut session = s.lock().await;
+                client
+                    .list_databases_with_session(None, None, &mut session)
+                    .await
+                    .map(|dbs| dbs.into_iter().map(|db| db.name).collect())
+            }
+            None => client.list_database_names(None, None).await,
+        };
         match database_names {
             Ok(v) => Ok(v),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn watch<'a>(
+    py: Python<'a>,
+    client: &Client,
+    mut pipeline: Option<Vec<Document>>,
+    mut options: Option<ChangeStreamOptions>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    let codec = client.2.clone();
+    codec.encode(&mut pipeline)?;
+    codec.encode(&mut options)?;
+    let client = client.0.clone();
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, ChangeStream>(py, async move {
+        let pipeline = pipeline.unwrap_or_default().into_iter().map(|d| d.0);
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let target = interface::WatchTarget::Client(client);
+        let result = interface::watch(target, pipeline, options, session).await;
+        match result {
+            Ok(v) => Ok(ChangeStream::new(v, codec)),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
-pub fn create_session<'a>(py: Python<'a>, client: &Client) -> PyResult<&'a PyAny> {
+#[allow(clippy::too_many_arguments)]
+pub fn watch_with_checkpoints<'a>(
+    py: Python<'a>,
+    client: &Client,
+    checkpoints: Collection,
+    consumer_name: String,
+    pipeline: Option<Vec<Document>>,
+    options: Option<ChangeStreamOptions>,
+    checkpoint_every: Option<usize>,
+    checkpoint_interval: Option<u64>,
+    max_retries: Option<u32>,
+) -> PyResult<&'a PyAny> {
+    change_stream_binding::watch_with_checkpoints(
+        py,
+        interface::WatchTarget::Client(client.0.clone()),
+        client.2.clone(),
+        checkpoints,
+        consumer_name,
+        pipeline,
+        options,
+        checkpoint_every,
+        checkpoint_interval,
+        max_retries,
+    )
+}
+
+#[pyfunction]
+pub fn create_session<'a>(
+    py: Python<'a>,
+    client: &Client,
+    options: Option<SessionOptions>,
+) -> PyResult<&'a PyAny> {
     let client = client.0.clone();
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, ClientSession>(py, async move {
-        let session = client.start_session(None).await;
+        let session = client.start_session(options).await;
         match session {
-            Ok(v) => Ok(ClientSession(Arc::new(Mutex::new(v)))),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(v) => Ok(ClientSession::new(v)),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn start_transaction<'a>(
+    py: Python<'a>,
+    session: &ClientSession,
+    options: Option<TransactionOptions>,
+) -> PyResult<&'a PyAny> {
+    let inner = session.session()?;
+    let session = session.clone();
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+        let result = inner.lock().await.start_transaction(options).await;
+        match result {
+            Ok(v) => {
+                session.set_in_transaction(true);
+                Ok(v)
+            }
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn commit_transaction<'a>(py: Python<'a>, session: &ClientSession) -> PyResult<&'a PyAny> {
+    let inner = session.session()?;
+    let session = session.clone();
+    pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+        let result = inner.lock().await.commit_transaction().await;
+        session.set_in_transaction(false);
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn abort_transaction<'a>(py: Python<'a>, session: &ClientSession) -> PyResult<&'a PyAny> {
+    let inner = session.session()?;
+    let session = session.clone();
+    pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+        let result = inner.lock().await.abort_transaction().await;
+        session.set_in_transaction(false);
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+fn has_label(e: &PyErr, label: &str) -> bool {
+    Python::with_gil(|py| {
+        e.value(py)
+            .getattr("error_labels")
+            .and_then(|labels| labels.extract::<Vec<String>>())
+            .is_ok_and(|labels| labels.iter().any(|l| l == label))
+    })
+}
+
+fn is_max_time_expired(e: &mongodb::error::Error) -> bool {
+    match e.kind.as_ref() {
+        ErrorKind::Command(err) => err.code == 50,
+        ErrorKind::Write(WriteFailure::WriteConcernError(err)) => err.code == 50,
+        _ => false,
+    }
+}
+
+/// Runs `callback` in a transaction and commits it. The whole transaction is retried while the
+/// callback or the commit fails with a "TransientTransactionError" label, and the commit alone is
+/// retried on "UnknownTransactionCommitResult", until the timeout runs out.
+#[pyfunction]
+pub fn with_transaction<'a>(
+    py: Python<'a>,
+    session: &ClientSession,
+    callback: PyObject,
+    options: Option<TransactionOptions>,
+    timeout: Option<TimeoutValue>,
+) -> PyResult<&'a PyAny> {
+    let inner = session.session()?;
+    let session = session.clone();
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
+    let deadline = timeout
+        .map_or(WITH_TRANSACTION_TIMEOUT, Into::into)
+        .duration()
+        .map(|d| Instant::now() + d);
+    pyo3_asyncio::tokio::future_into_py::<_, PyObject>(py, async move {
+        let expired = || deadline.is_some_and(|d| Instant::now() >= d);
+        'transaction: loop {
+            let result = inner.lock().await.start_transaction(options.clone()).await;
+            if let Err(e) = result {
+                return Err(to_py_err(e));
+            }
+            session.set_in_transaction(true);
+            let result = match Python::with_gil(|py| {
+                pyo3_asyncio::tokio::into_future(callback.as_ref(py).call0()?)
+            }) {
+                Ok(future) => future.await,
+                Err(e) => Err(e),
+            };
+            let value = match result {
+                Ok(v) => v,
+                Err(e) => {
+                    // Only fails when the callback already ended the transaction.
+                    let _ = inner.lock().await.abort_transaction().await;
+                    session.set_in_transaction(false);
+                    if has_label(&e, TRANSIENT_TRANSACTION_ERROR) && !expired() {
+                        continue 'transaction;
+                    }
+                    return Err(e);
+                }
+            };
+            loop {
+                let result = inner.lock().await.commit_transaction().await;
+                session.set_in_transaction(false);
+                match result {
+                    Ok(_) => return Ok(value),
+                    Err(e) if is_max_time_expired(&e) || expired() => return Err(to_py_err(e)),
+                    Err(e) if e.contains_label(UNKNOWN_TRANSACTION_COMMIT_RESULT) => continue,
+                    Err(e) if e.contains_label(TRANSIENT_TRANSACTION_ERROR) => {
+                        continue 'transaction
+                    }
+                    Err(e) => return Err(to_py_err(e)),
+                }
+            }
+        }
+    })
+}
+
+/// Aborts the transaction in progress, if any, and ends the session. Its server session goes back
+/// to the client's pool once the cursors still using it are dropped. Ending a session twice does
+/// nothing.
+#[pyfunction]
+pub fn end_session<'a>(py: Python<'a>, session: &ClientSession) -> PyResult<&'a PyAny> {
+    let inner = session.0.lock().unwrap().take();
+    let session = session.clone();
+    pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+        if let Some(inner) = inner {
+            if session.in_transaction() {
+                // Only fails when the server already ended the transaction.
+                let _ = inner.lock().await.abort_transaction().await;
+                session.set_in_transaction(false);
+            }
+        }
+        Ok(())
+    })
+}
+
+#[pyfunction]
+pub fn session_id<'a>(py: Python<'a>, session: &ClientSession) -> PyResult<&'a PyAny> {
+    let session = session.session()?;
+    pyo3_asyncio::tokio::future_into_py::<_, Document>(py, async move {
+        Ok(Document::from(session.lock().await.id().clone()))
+    })
+}
+
+#[pyfunction]
+pub fn cluster_time<'a>(py: Python<'a>, session: &ClientSession) -> PyResult<&'a PyAny> {
+    let session = session.session()?;
+    pyo3_asyncio::tokio::future_into_py::<_, Option<Document>>(py, async move {
+        let session = session.lock().await;
+        match session.cluster_time().map(mongodb::bson::to_document) {
+            Some(Ok(v)) => Ok(Some(Document::from(v))),
+            Some(Err(e)) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            None => Ok(None),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn operation_time<'a>(py: Python<'a>, session: &ClientSession) -> PyResult<&'a PyAny> {
+    let session = session.session()?;
+    pyo3_asyncio::tokio::future_into_py::<_, Option<Bson>>(py, async move {
+        let operation_time = session.lock().await.operation_time();
+        Ok(operation_time.map(|ts| Bson(mongodb::bson::Bson::Timestamp(ts))))
+    })
+}
+
+/// Moves the session's cluster time forward to one seen by another session. Earlier cluster
+/// times are ignored.
+#[pyfunction]
+pub fn advance_cluster_time<'a>(
+    py: Python<'a>,
+    session: &ClientSession,
+    cluster_time: Document,
+) -> PyResult<&'a PyAny> {
+    let session = session.session()?;
+    let cluster_time = match mongodb::bson::from_document::<mongodb::ClusterTime>(cluster_time.0) {
+        Ok(v) => v,
+        Err(e) => return Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+        session.lock().await.advance_cluster_time(&cluster_time);
+        Ok(())
+    })
+}
+
+/// Moves the session's operation time forward to one seen by another session. Earlier operation
+/// times are ignored.
+#[pyfunction]
+pub fn advance_operation_time<'a>(
+    py: Python<'a>,
+    session: &ClientSession,
+    operation_time: Bson,
+) -> PyResult<&'a PyAny> {
+    let session = session.session()?;
+    let operation_time = match operation_time.0 {
+        mongodb::bson::Bson::Timestamp(ts) => ts,
+        _ => {
+            return Err(PyErr::new::<exceptions::PyTypeError, _>(
+                "Operation time must be a Timestamp",
+            ))
         }
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+        session.lock().await.advance_operation_time(operation_time);
+        Ok(())
     })
 }
 
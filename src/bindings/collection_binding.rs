@@ -1,37 +1,69 @@
-use std::sync::Arc;
-
 use mongodb::{
     self,
     bson::{self},
 };
 
 use pyo3::{exceptions, prelude::*};
-use tokio::sync::Mutex;
 
 use crate::interface;
 
 use super::client_binding::ClientSession;
 
-use super::bson_binding::Bson;
+use super::bson_binding::{Bson, EncodeUuids};
+use super::change_stream_binding::{self, ChangeStream};
 use super::document_binding::Document;
+use super::errors_binding;
 use super::index_binding::IndexModel;
+use super::options_binding::{
+    AggregateOptions, ChangeStreamOptions, CodecOptions, CountOptions, DeleteOptions, FindOptions,
+    InsertOptions, ReturnDocument, TimeoutValue, UpdateOptions,
+};
 use super::results_binding::*;
+use super::utils::{to_py_err, to_py_err_with_inserted_ids};
+use super::write_model_binding::{UpdateModifications, WriteModel};
 
 #[pyclass(frozen)]
-#[repr(transparent)]
 #[derive(Clone)]
-pub struct Collection(pub mongodb::Collection<bson::Document>);
+pub struct Collection(
+    pub mongodb::Collection<bson::Document>,
+    pub(crate) interface::Timeout,
+    pub(crate) CodecOptions,
+);
+
+impl Collection {
+    /// Resolves the timeout of a call, falling back to the one configured on the collection.
+    pub(crate) fn timeout(&self, timeout: Option<TimeoutValue>) -> interface::Timeout {
+        timeout.map_or(self.1, Into::into)
+    }
+
+    /// Encodes the UUIDs of a value sent to the server in the collection's UUID representation.
+    pub(crate) fn encode(&self, value: &mut impl EncodeUuids) -> PyResult<()> {
+        self.2.encode(value)
+    }
+}
 
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 pub fn find_one<'a>(
     py: Python<'a>,
     collection: Collection,
-    filter: Document,
-    sort: Option<Document>,
-    projection: Option<Document>,
-    timeout: Option<u64>,
+    mut filter: Document,
+    skip: Option<u64>,
+    mut sort: Option<Document>,
+    mut projection: Option<Document>,
+    mut options: Option<FindOptions>,
+    timeout: Option<TimeoutValue>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    collection.encode(&mut sort)?;
+    collection.encode(&mut projection)?;
+    collection.encode(&mut options)?;
+    let timeout = collection.timeout(timeout);
+    let options = match options {
+        Some(o) => Some(o.to_find_one_options()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, Option<Document>>(py, async move {
         let sort = match sort {
             Some(s) => Some(s.0),
@@ -42,42 +74,55 @@ pub fn find_one<'a>(
             None => None,
         };
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
         let result = interface::find_one(
             collection.0,
             filter.0,
-            None,
+            skip,
             sort,
             projection,
+            options,
             timeout,
             session,
         )
         .await;
         match result {
             Ok(d) => Ok(match d {
-                Some(d) => Some(Document(d)),
+                Some(d) => Some(Document(d, collection.2)),
                 None => None,
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 pub fn find_many<'a>(
     py: Python<'a>,
     collection: Collection,
-    filter: Option<Document>,
+    mut filter: Option<Document>,
     skip: Option<u64>,
     limit: Option<i64>,
-    sort: Option<Document>,
+    mut sort: Option<Document>,
     batch_size: Option<u32>,
-    projection: Option<Document>,
-    timeout: Option<u64>,
+    mut projection: Option<Document>,
+    mut options: Option<FindOptions>,
+    timeout: Option<TimeoutValue>,
     session: Option<ClientSession>,
+    prefetch: Option<usize>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    collection.encode(&mut sort)?;
+    collection.encode(&mut projection)?;
+    collection.encode(&mut options)?;
+    let timeout = collection.timeout(timeout);
+    let options = match options {
+        Some(o) => Some(o.to_find_options()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, DocumentResultIterator>(py, async move {
         let filter = match filter {
             Some(f) => Some(f.0),
@@ -92,7 +137,7 @@ pub fn find_many<'a>(
             None => None,
         };
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
         let result = interface::find_many(
@@ -103,13 +148,171 @@ pub fn find_many<'a>(
             sort,
             batch_size,
             projection,
+            options,
+            timeout,
+            session,
+        )
+        .await;
+        match result {
+            Ok(mut v) => {
+                if let Some(buffer) = prefetch {
+                    v.prefetch(buffer);
+                }
+                Ok(DocumentResultIterator::new(v, collection.2))
+            }
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn find_one_and_update<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut update: UpdateModifications,
+    mut filter: Document,
+    return_document: Option<ReturnDocument>,
+    mut sort: Option<Document>,
+    mut projection: Option<Document>,
+    upsert: Option<bool>,
+    mut array_filters: Option<Vec<Document>>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    collection.encode(&mut update)?;
+    collection.encode(&mut filter)?;
+    collection.encode(&mut array_filters)?;
+    collection.encode(&mut sort)?;
+    collection.encode(&mut projection)?;
+    let timeout = collection.timeout(timeout);
+    pyo3_asyncio::tokio::future_into_py::<_, Option<Document>>(py, async move {
+        let return_document = return_document.map(Into::into);
+        let sort = match sort {
+            Some(s) => Some(s.0),
+            None => None,
+        };
+        let projection = match projection {
+            Some(p) => Some(p.0),
+            None => None,
+        };
+        let array_filters =
+            array_filters.map(|array_filters| array_filters.into_iter().map(|d| d.0).collect());
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::find_one_and_update(
+            collection.0,
+            update.into(),
+            filter.0,
+            return_document,
+            sort,
+            projection,
+            upsert,
+            array_filters,
             timeout,
             session,
         )
         .await;
         match result {
-            Ok(v) => Ok(DocumentResultIterator(Arc::new(Mutex::new(v)))),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(d) => Ok(d.map(|d| Document(d, collection.2))),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn find_one_and_replace<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut replacement: Document,
+    mut filter: Document,
+    return_document: Option<ReturnDocument>,
+    mut sort: Option<Document>,
+    mut projection: Option<Document>,
+    upsert: Option<bool>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    collection.encode(&mut replacement)?;
+    collection.encode(&mut filter)?;
+    collection.encode(&mut sort)?;
+    collection.encode(&mut projection)?;
+    let timeout = collection.timeout(timeout);
+    pyo3_asyncio::tokio::future_into_py::<_, Option<Document>>(py, async move {
+        let return_document = return_document.map(Into::into);
+        let sort = match sort {
+            Some(s) => Some(s.0),
+            None => None,
+        };
+        let projection = match projection {
+            Some(p) => Some(p.0),
+            None => None,
+        };
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::find_one_and_replace(
+            collection.0,
+            replacement.0,
+            filter.0,
+            return_document,
+            sort,
+            projection,
+            upsert,
+            timeout,
+            session,
+        )
+        .await;
+        match result {
+            Ok(d) => Ok(d.map(|d| Document(d, collection.2))),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn find_one_and_delete<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut filter: Document,
+    mut sort: Option<Document>,
+    mut projection: Option<Document>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    collection.encode(&mut sort)?;
+    collection.encode(&mut projection)?;
+    let timeout = collection.timeout(timeout);
+    pyo3_asyncio::tokio::future_into_py::<_, Option<Document>>(py, async move {
+        let sort = match sort {
+            Some(s) => Some(s.0),
+            None => None,
+        };
+        let projection = match projection {
+            Some(p) => Some(p.0),
+            None => None,
+        };
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::find_one_and_delete(
+            collection.0,
+            filter.0,
+            sort,
+            projection,
+            timeout,
+            session,
+        )
+        .await;
+        match result {
+            Ok(d) => Ok(d.map(|d| Document(d, collection.2))),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -118,22 +321,26 @@ pub fn find_many<'a>(
 pub fn insert_one<'a>(
     py: Python<'a>,
     collection: Collection,
-    document: Document,
+    mut document: Document,
+    mut options: Option<InsertOptions>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut document)?;
+    collection.encode(&mut options)?;
+    let options = options.map(|o| o.to_insert_one_options());
     pyo3_asyncio::tokio::future_into_py::<_, InsertOneResult>(py, async move {
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
-        let result = interface::insert_one(collection.0, document.0, session).await;
+        let result = interface::insert_one(collection.0, document.0, options, session).await;
         match result {
             Ok(v) => Python::with_gil(|p| {
                 Ok(InsertOneResult {
-                    inserted_id: Bson(v.inserted_id).into_py(p),
+                    inserted_id: Bson(v.inserted_id).into_object(p, "_id", &collection.2)?,
                 })
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -142,63 +349,84 @@ pub fn insert_one<'a>(
 pub fn insert_many<'a>(
     py: Python<'a>,
     collection: Collection,
-    documents: Vec<Document>,
+    mut documents: Vec<Document>,
+    mut options: Option<InsertOptions>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut documents)?;
+    collection.encode(&mut options)?;
+    let options = options.map(|o| o.to_insert_many_options());
     pyo3_asyncio::tokio::future_into_py::<_, InsertManyResult>(py, async move {
         let docs = documents.into_iter().map(|d| d.0);
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
-        let result = interface::insert_many(collection.0, docs, session).await;
+        let result = interface::insert_many(collection.0, docs, options, session).await;
         match result {
             Ok(v) => Python::with_gil(|p| {
                 Ok(InsertManyResult {
                     inserted_ids: v
                         .inserted_ids
                         .into_iter()
-                        .map(|(_, id)| Bson(id).into_py(p))
-                        .collect::<Vec<PyObject>>(),
+                        .map(|(i, id)| Bson(id).into_object(p, &i.to_string(), &collection.2))
+                        .collect::<PyResult<Vec<PyObject>>>()?,
                 })
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err_with_inserted_ids(
+                e.error,
+                e.inserted_ids,
+                &collection.2,
+            )),
         }
     })
 }
 
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 pub fn update_one<'a>(
     py: Python<'a>,
     collection: Collection,
-    update: Document,
-    filter: Document,
+    mut update: UpdateModifications,
+    mut filter: Document,
     upsert: Option<bool>,
-    array_filters: Option<Vec<Document>>,
+    mut array_filters: Option<Vec<Document>>,
+    mut options: Option<UpdateOptions>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut update)?;
+    collection.encode(&mut filter)?;
+    collection.encode(&mut array_filters)?;
+    collection.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_update_options()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, UpdateResult>(py, async move {
         let array_filters = match array_filters {
             Some(array_filters) => Some(array_filters.into_iter().map(|d| d.0).collect()),
             None => None,
         };
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
         let result = interface::update_one(
             collection.0,
-            update.0,
+            update.into(),
             filter.0,
             upsert,
             array_filters,
+            options,
             session,
         )
         .await;
         match result {
             Ok(v) => Python::with_gil(|p| {
                 let upserted_id = match v.upserted_id {
-                    Some(upserted_id) => Some(Bson(upserted_id).into_py(p)),
+                    Some(upserted_id) => {
+                        Some(Bson(upserted_id).into_object(p, "_id", &collection.2)?)
+                    }
                     None => None,
                 };
 
@@ -208,7 +436,111 @@ pub fn update_one<'a>(
                     upserted_id,
                 })
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn update_many<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut update: UpdateModifications,
+    mut filter: Document,
+    upsert: Option<bool>,
+    mut array_filters: Option<Vec<Document>>,
+    mut options: Option<UpdateOptions>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    collection.encode(&mut update)?;
+    collection.encode(&mut filter)?;
+    collection.encode(&mut array_filters)?;
+    collection.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_update_options()?),
+        None => None,
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, UpdateResult>(py, async move {
+        let array_filters =
+            array_filters.map(|array_filters| array_filters.into_iter().map(|d| d.0).collect());
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::update_many(
+            collection.0,
+            update.into(),
+            filter.0,
+            upsert,
+            array_filters,
+            options,
+            session,
+        )
+        .await;
+        match result {
+            Ok(v) => Python::with_gil(|p| {
+                let upserted_id = v
+                    .upserted_id
+                    .map(|upserted_id| Bson(upserted_id).into_object(p, "_id", &collection.2))
+                    .transpose()?;
+
+                Ok(UpdateResult {
+                    matched_count: v.matched_count,
+                    modified_count: v.modified_count,
+                    upserted_id,
+                })
+            }),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn replace_one<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut replacement: Document,
+    mut filter: Document,
+    upsert: Option<bool>,
+    mut options: Option<UpdateOptions>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    collection.encode(&mut replacement)?;
+    collection.encode(&mut filter)?;
+    collection.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_replace_options()?),
+        None => None,
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, UpdateResult>(py, async move {
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::replace_one(
+            collection.0,
+            replacement.0,
+            filter.0,
+            upsert,
+            options,
+            session,
+        )
+        .await;
+        match result {
+            Ok(v) => Python::with_gil(|p| {
+                let upserted_id = v
+                    .upserted_id
+                    .map(|upserted_id| Bson(upserted_id).into_object(p, "_id", &collection.2))
+                    .transpose()?;
+
+                Ok(UpdateResult {
+                    matched_count: v.matched_count,
+                    modified_count: v.modified_count,
+                    upserted_id,
+                })
+            }),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -217,20 +549,27 @@ pub fn update_one<'a>(
 pub fn delete_one<'a>(
     py: Python<'a>,
     collection: Collection,
-    filter: Document,
+    mut filter: Document,
+    mut options: Option<DeleteOptions>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    collection.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, DeleteResult>(py, async move {
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
-        let result = interface::delete_one(collection.0, filter.0, session).await;
+        let result = interface::delete_one(collection.0, filter.0, options, session).await;
         match result {
             Ok(v) => Ok(DeleteResult {
                 deleted_count: v.deleted_count,
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -239,74 +578,232 @@ pub fn delete_one<'a>(
 pub fn delete_many<'a>(
     py: Python<'a>,
     collection: Collection,
-    filter: Option<Document>,
+    mut filter: Option<Document>,
+    mut options: Option<DeleteOptions>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    collection.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, DeleteResult>(py, async move {
         let filter = match filter {
             Some(f) => f.0,
             None => bson::Document::new(),
         };
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
-        let result = interface::delete_many(collection.0, filter, session).await;
+        let result = interface::delete_many(collection.0, filter, options, session).await;
         match result {
             Ok(v) => Ok(DeleteResult {
                 deleted_count: v.deleted_count,
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn bulk_write<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut requests: Vec<WriteModel>,
+    ordered: Option<bool>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    if requests.is_empty() {
+        return Err(PyErr::new::<exceptions::PyValueError, _>(
+            "A bulk write needs at least one request",
+        ));
+    }
+    collection.encode(&mut requests)?;
+    pyo3_asyncio::tokio::future_into_py::<_, BulkWriteResult>(py, async move {
+        let models = requests.into_iter().map(|m| m.into()).collect();
+        let in_transaction = session.as_ref().is_some_and(|s| s.in_transaction());
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result =
+            interface::bulk_write(collection.0, models, ordered, session, in_transaction).await;
+        match result {
+            Ok(v) => Python::with_gil(|p| {
+                let write_errors = v
+                    .write_errors
+                    .into_iter()
+                    .map(|e| WriteErrorResult::new(p, e))
+                    .collect();
+                let write_concern_errors = v
+                    .write_concern_errors
+                    .into_iter()
+                    .map(|e| WriteConcernErrorResult::new(p, e))
+                    .collect();
+
+                let result = BulkWriteResult {
+                    inserted_count: v.inserted_count,
+                    matched_count: v.matched_count,
+                    modified_count: v.modified_count,
+                    deleted_count: v.deleted_count,
+                    upserted_count: v.upserted_count,
+                    inserted_ids: v
+                        .inserted_ids
+                        .into_iter()
+                        .map(|(i, id)| {
+                            Ok((i, Bson(id).into_object(p, &i.to_string(), &collection.2)?))
+                        })
+                        .collect::<PyResult<_>>()?,
+                    upserted_ids: v
+                        .upserted_ids
+                        .into_iter()
+                        .map(|(i, id)| {
+                            Ok((i, Bson(id).into_object(p, &i.to_string(), &collection.2)?))
+                        })
+                        .collect::<PyResult<_>>()?,
+                    write_errors,
+                    write_concern_errors,
+                };
+                if result.write_errors.is_empty() && result.write_concern_errors.is_empty() {
+                    return Ok(result);
+                }
+                match errors_binding::new_bulk_write_error(p, result) {
+                    Ok(err) => Err(err),
+                    Err(err) => Err(err),
+                }
+            }),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 pub fn aggregate<'a>(
     py: Python<'a>,
     collection: Collection,
-    pipeline: Vec<Document>,
+    mut pipeline: Vec<Document>,
     batch_size: Option<u32>,
-    timeout: Option<u64>,
+    mut options: Option<AggregateOptions>,
+    timeout: Option<TimeoutValue>,
     session: Option<ClientSession>,
+    prefetch: Option<usize>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut pipeline)?;
+    collection.encode(&mut options)?;
+    let timeout = collection.timeout(timeout);
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, DocumentResultIterator>(py, async move {
         let docs = pipeline.into_iter().map(|d| d.0);
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result =
+            interface::aggregate(collection.0, docs, batch_size, options, timeout, session).await;
+        match result {
+            Ok(mut v) => {
+                if let Some(buffer) = prefetch {
+                    v.prefetch(buffer);
+                }
+                Ok(DocumentResultIterator::new(v, collection.2))
+            }
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn watch<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    mut pipeline: Option<Vec<Document>>,
+    mut options: Option<ChangeStreamOptions>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    collection.encode(&mut pipeline)?;
+    collection.encode(&mut options)?;
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, ChangeStream>(py, async move {
+        let pipeline = pipeline.unwrap_or_default().into_iter().map(|d| d.0);
+        let session = match session {
+            Some(s) => Some(s.session()?),
             None => None,
         };
-        let result = interface::aggregate(collection.0, docs, batch_size, timeout, session).await;
+        let target = interface::WatchTarget::Collection(collection.0);
+        let result = interface::watch(target, pipeline, options, session).await;
         match result {
-            Ok(v) => Ok(DocumentResultIterator(Arc::new(Mutex::new(v)))),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(v) => Ok(ChangeStream::new(v, collection.2)),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn watch_with_checkpoints<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    checkpoints: Collection,
+    consumer_name: String,
+    pipeline: Option<Vec<Document>>,
+    options: Option<ChangeStreamOptions>,
+    checkpoint_every: Option<usize>,
+    checkpoint_interval: Option<u64>,
+    max_retries: Option<u32>,
+) -> PyResult<&'a PyAny> {
+    change_stream_binding::watch_with_checkpoints(
+        py,
+        interface::WatchTarget::Collection(collection.0),
+        collection.2,
+        checkpoints,
+        consumer_name,
+        pipeline,
+        options,
+        checkpoint_every,
+        checkpoint_interval,
+        max_retries,
+    )
+}
+
 #[pyfunction]
 pub fn distinct<'a>(
     py: Python<'a>,
     collection: Collection,
     field_name: String,
-    filter: Option<Document>,
-    timeout: Option<u64>,
+    mut filter: Option<Document>,
+    timeout: Option<TimeoutValue>,
     session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    let timeout = collection.timeout(timeout);
     pyo3_asyncio::tokio::future_into_py::<_, Vec<PyObject>>(py, async move {
         let filter = match filter {
             Some(v) => Some(v.0),
             None => None,
         };
         let session = match session {
-            Some(s) => Some(s.0),
+            Some(s) => Some(s.session()?),
             None => None,
         };
         let result =
             interface::distinct(collection.0, field_name.as_str(), filter, timeout, session).await;
         match result {
-            Ok(v) => Python::with_gil(|p| Ok(v.into_iter().map(|b| Bson(b).into_py(p)).collect())),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(v) => Python::with_gil(|p| {
+                v.into_iter()
+                    .enumerate()
+                    .map(|(i, b)| Bson(b).into_object(p, &i.to_string(), &collection.2))
+                    .collect()
+            }),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -315,13 +812,19 @@ pub fn distinct<'a>(
 pub fn list_indexes<'a>(
     py: Python<'a>,
     collection: Collection,
-    timeout: Option<u64>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    let timeout = collection.timeout(timeout);
     pyo3_asyncio::tokio::future_into_py::<_, IndexResultIterator>(py, async move {
-        let result = interface::list_indexes(collection.0, timeout).await;
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::list_indexes(collection.0, timeout, session).await;
         match result {
-            Ok(v) => Ok(IndexResultIterator(Arc::new(Mutex::new(v)))),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(v) => Ok(IndexResultIterator::from(v)),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -330,9 +833,12 @@ pub fn list_indexes<'a>(
 pub fn create_indexes<'a>(
     py: Python<'a>,
     collection: Collection,
-    indexes: Vec<IndexModel>,
-    timeout: Option<u64>,
+    mut indexes: Vec<IndexModel>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut indexes)?;
+    let timeout = collection.timeout(timeout);
     pyo3_asyncio::tokio::future_into_py::<_, CreateIndexesResult>(py, async move {
         let mut mongo_indexes = Vec::with_capacity(indexes.len());
         for py_idx in indexes {
@@ -373,13 +879,18 @@ pub fn create_indexes<'a>(
             mongo_indexes.push(mongo_idx);
         }
 
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
         let result =
-            interface::create_indexes(collection.0, mongo_indexes.into_iter(), timeout).await;
+            interface::create_indexes(collection.0, mongo_indexes.into_iter(), timeout, session)
+                .await;
         match result {
             Ok(v) => Ok(CreateIndexesResult {
                 index_names: v.index_names,
             }),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -389,48 +900,103 @@ pub fn drop_indexes<'a>(
     py: Python<'a>,
     collection: Collection,
     indexes: Option<Vec<String>>,
-    timeout: Option<u64>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    let timeout = collection.timeout(timeout);
     pyo3_asyncio::tokio::future_into_py(py, async move {
         let indexes = match indexes {
             Some(idxs) => Some(idxs.into_iter()),
             None => None,
         };
-        let result = interface::drop_indexes(collection.0, indexes, timeout).await;
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::drop_indexes(collection.0, indexes, timeout, session).await;
         match result {
             Ok(_) => Ok(()),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
+#[allow(clippy::too_many_arguments)]
 pub fn count_documents<'a>(
     py: Python<'a>,
     collection: Collection,
-    filter: Option<Document>,
-    timeout: Option<u64>,
+    mut filter: Option<Document>,
+    skip: Option<u64>,
+    limit: Option<u64>,
+    mut options: Option<CountOptions>,
+    timeout: Option<TimeoutValue>,
+    session: Option<ClientSession>,
 ) -> PyResult<&'a PyAny> {
+    collection.encode(&mut filter)?;
+    collection.encode(&mut options)?;
+    let timeout = collection.timeout(timeout);
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
     pyo3_asyncio::tokio::future_into_py::<_, u64>(py, async move {
         let filter = match filter {
             Some(f) => Some(f.0),
             None => None,
         };
-        let result = interface::count_documents(collection.0, filter, timeout).await;
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::count_documents(
+            collection.0,
+            filter,
+            skip,
+            limit,
+            options,
+            timeout,
+            session,
+        )
+        .await;
+        match result {
+            Ok(v) => Ok(v),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+pub fn estimated_document_count<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    timeout: Option<TimeoutValue>,
+) -> PyResult<&'a PyAny> {
+    let timeout = collection.timeout(timeout);
+    pyo3_asyncio::tokio::future_into_py::<_, u64>(py, async move {
+        let result = interface::estimated_document_count(collection.0, timeout).await;
         match result {
             Ok(v) => Ok(v),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
-pub fn drop<'a>(py: Python<'a>, collection: Collection) -> PyResult<&'a PyAny> {
+pub fn drop<'a>(
+    py: Python<'a>,
+    collection: Collection,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
     pyo3_asyncio::tokio::future_into_py(py, async move {
-        let result = interface::drop(collection.0).await;
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let result = interface::drop(collection.0, session).await;
         match result {
             Ok(_) => Ok(()),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
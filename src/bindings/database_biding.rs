@@ -1,38 +1,173 @@
 use mongodb;
 
-use pyo3::{exceptions, prelude::*};
+use pyo3::prelude::*;
 
+use crate::interface;
+
+use super::change_stream_binding::{self, ChangeStream};
+use super::client_binding::ClientSession;
 use super::collection_binding::Collection;
+use super::document_binding::Document;
+use super::options_binding::{ChangeStreamOptions, CodecOptions, TimeoutValue};
+use super::utils::to_py_err;
 
 #[pyclass]
-#[repr(transparent)]
-pub struct Database(pub(crate) mongodb::Database);
+pub struct Database(
+    pub(crate) mongodb::Database,
+    pub(crate) interface::Timeout,
+    pub(crate) CodecOptions,
+);
 
 #[pyfunction]
-pub fn collection(db: &Database, collection_name: String) -> Collection {
-    Collection(db.0.collection(collection_name.as_str()))
+pub fn collection(
+    db: &Database,
+    collection_name: String,
+    timeout: Option<TimeoutValue>,
+    codec_options: Option<CodecOptions>,
+) -> Collection {
+    Collection(
+        db.0.collection(collection_name.as_str()),
+        timeout.map_or(db.1, Into::into),
+        codec_options.unwrap_or_else(|| db.2.clone()),
+    )
 }
 
 #[pyfunction]
-pub fn list_collections<'a>(py: Python<'a>, db: &Database) -> PyResult<&'a PyAny> {
+pub fn list_collections<'a>(
+    py: Python<'a>,
+    db: &Database,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
     let db = db.0.clone();
     pyo3_asyncio::tokio::future_into_py::<_, Vec<String>>(py, async move {
-        let result = db.list_collection_names(None).await;
+        let result = match session {
+            Some(s) => {
+                let s = s.session()?;
+                let mut session = s.lock().await;
+                db.list_collection_names_with_session(None, &mut session)
+                    .await
+            }
+            None => db.list_collection_names(None).await,
+        };
         match result {
             Ok(v) => Ok(v),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+/// Creates the collection. A capped collection keeps at most `size` bytes and, when set,
+/// `max_documents` documents, dropping the oldest ones first.
+#[pyfunction]
+pub fn create_collection<'a>(
+    py: Python<'a>,
+    db: &Database,
+    collection_name: String,
+    capped: Option<bool>,
+    size: Option<u64>,
+    max_documents: Option<u64>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    let db = db.0.clone();
+    let options = mongodb::options::CreateCollectionOptions::builder()
+        .capped(capped)
+        .size(size)
+        .max(max_documents)
+        .build();
+    pyo3_asyncio::tokio::future_into_py(py, async move {
+        let result = match session {
+            Some(s) => {
+                let s = s.session()?;
+                let mut session = s.lock().await;
+                db.create_collection_with_session(collection_name, options, &mut session)
+                    .await
+            }
+            None => db.create_collection(collection_name, options).await,
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
 
 #[pyfunction]
-pub fn drop<'a>(py: Python<'a>, db: &Database) -> PyResult<&'a PyAny> {
+pub fn drop<'a>(
+    py: Python<'a>,
+    db: &Database,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
     let db = db.0.clone();
     pyo3_asyncio::tokio::future_into_py(py, async move {
-        let result = db.drop(None).await;
+        let result = match session {
+            Some(s) => {
+                let s = s.session()?;
+                let mut session = s.lock().await;
+                db.drop_with_session(None, &mut session).await
+            }
+            None => db.drop(None).await,
+        };
         match result {
             Ok(_) => Ok(()),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
+
+#[pyfunction]
+pub fn watch<'a>(
+    py: Python<'a>,
+    db: &Database,
+    mut pipeline: Option<Vec<Document>>,
+    mut options: Option<ChangeStreamOptions>,
+    session: Option<ClientSession>,
+) -> PyResult<&'a PyAny> {
+    let codec = db.2.clone();
+    codec.encode(&mut pipeline)?;
+    codec.encode(&mut options)?;
+    let db = db.0.clone();
+    let options = match options {
+        Some(o) => Some(o.to_mongodb()?),
+        None => None,
+    };
+    pyo3_asyncio::tokio::future_into_py::<_, ChangeStream>(py, async move {
+        let pipeline = pipeline.unwrap_or_default().into_iter().map(|d| d.0);
+        let session = match session {
+            Some(s) => Some(s.session()?),
+            None => None,
+        };
+        let target = interface::WatchTarget::Database(db);
+        let result = interface::watch(target, pipeline, options, session).await;
+        match result {
+            Ok(v) => Ok(ChangeStream::new(v, codec)),
+            Err(e) => Err(to_py_err(e)),
+        }
+    })
+}
+
+#[pyfunction]
+#[allow(clippy::too_many_arguments)]
+pub fn watch_with_checkpoints<'a>(
+    py: Python<'a>,
+    db: &Database,
+    checkpoints: Collection,
+    consumer_name: String,
+    pipeline: Option<Vec<Document>>,
+    options: Option<ChangeStreamOptions>,
+    checkpoint_every: Option<usize>,
+    checkpoint_interval: Option<u64>,
+    max_retries: Option<u32>,
+) -> PyResult<&'a PyAny> {
+    change_stream_binding::watch_with_checkpoints(
+        py,
+        interface::WatchTarget::Database(db.0.clone()),
+        db.2.clone(),
+        checkpoints,
+        consumer_name,
+        pipeline,
+        options,
+        checkpoint_every,
+        checkpoint_interval,
+        max_retries,
+    )
+}
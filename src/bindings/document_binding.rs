@@ -4,13 +4,21 @@ use mongodb::{self, bson};
 
 use pyo3::{self, exceptions, iter::IterNextOutput, prelude::*, types::PyDict};
 
-use super::bson_binding::Bson;
+use super::bson_binding::{child_path, Bson};
+use super::options_binding::CodecOptions;
 use super::utils::key_is_string;
 
+/// A BSON document. Values are converted to Python when they are read, following the codec
+/// options of the collection the document came from.
 #[pyclass(sequence, module = "ruson.types")]
-#[repr(transparent)]
 #[derive(Clone)]
-pub struct Document(pub(crate) bson::Document);
+pub struct Document(pub(crate) bson::Document, pub(crate) CodecOptions);
+
+impl From<bson::Document> for Document {
+    fn from(value: bson::Document) -> Self {
+        Document(value, CodecOptions::default())
+    }
+}
 
 #[pyclass(module = "ruson.types")]
 #[derive(Clone)]
@@ -55,7 +63,7 @@ impl Document {
     #[new]
     #[pyo3(signature = (dict=None, **kwargs))]
     pub fn new(dict: Option<HashMap<String, &PyAny>>, kwargs: Option<&PyDict>) -> PyResult<Self> {
-        let mut doc = Document(bson::Document::new());
+        let mut doc = Document::from(bson::Document::new());
         if let Some(dict) = dict {
             for (k, v) in dict.into_iter() {
                 doc.set(k, v)?;
@@ -74,7 +82,7 @@ impl Document {
     }
 
     pub fn copy(&self) -> Self {
-        Document(self.0.clone())
+        Document(self.0.clone(), self.1.clone())
     }
 
     pub fn clear(&mut self) {
@@ -103,14 +111,16 @@ impl Document {
         self.contains(key)
     }
 
-    pub fn get(&self, key: &PyAny) -> Option<PyObject> {
+    pub fn get(&self, key: &PyAny) -> PyResult<Option<PyObject>> {
         if key_is_string(key).is_err() {
-            None
-        } else {
-            match self.0.get(key.extract::<String>().unwrap()) {
-                Some(b) => Python::with_gil(|py| Some(Bson(b.clone()).into_py(py))),
-                None => None,
+            return Ok(None);
+        }
+        let key = key.extract::<String>()?;
+        match self.0.get(&key) {
+            Some(b) => {
+                Python::with_gil(|py| Bson(b.clone()).into_object(py, &key, &self.1).map(Some))
             }
+            None => Ok(None),
         }
     }
 
@@ -118,7 +128,7 @@ impl Document {
         key_is_string(key)?;
         let string_key = key.extract::<String>()?;
         match self.0.get(&string_key) {
-            Some(b) => Ok(Python::with_gil(|py| Bson(b.clone()).into_py(py))),
+            Some(b) => Python::with_gil(|py| Bson(b.clone()).into_object(py, &string_key, &self.1)),
             None => Err(PyErr::new::<exceptions::PyKeyError, _>(format!(
                 "Key not found: '{}'",
                 string_key
@@ -127,7 +137,7 @@ impl Document {
     }
 
     pub fn set(&mut self, key: String, value: &PyAny) -> PyResult<()> {
-        let value = Bson::extract(value)?.0;
+        let value = Bson::extract_at(value, &key)?.0;
         self.0.insert(key, value);
         Ok(())
     }
@@ -156,58 +166,67 @@ impl Document {
         Python::with_gil(|py| key_vector.into_py(py))
     }
 
-    pub fn values(&self) -> PyObject {
-        let values_vector = self
-            .0
-            .values()
-            .map(|k| Bson(k.clone()))
-            .collect::<Vec<Bson>>();
-
-        Python::with_gil(|py| values_vector.into_py(py))
+    pub fn values(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| {
+            let values_vector = self
+                .0
+                .iter()
+                .map(|(k, v)| Bson(v.clone()).into_object(py, k, &self.1))
+                .collect::<PyResult<Vec<PyObject>>>()?;
+            Ok(values_vector.into_py(py))
+        })
     }
 
-    pub fn items(&self) -> DocumentIter {
+    pub fn items(&self) -> PyResult<DocumentIter> {
         self.__iter__()
     }
 
-    pub fn __iter__(&self) -> DocumentIter {
-        let items_vector = self
-            .0
-            .iter()
-            .map(|tuple: (&String, &bson::Bson)| {
-                Python::with_gil(|py| (tuple.0.clone(), Bson(tuple.1.clone()).into_py(py)))
-            })
-            .collect::<Vec<(String, PyObject)>>();
+    pub fn __iter__(&self) -> PyResult<DocumentIter> {
+        let items_vector = Python::with_gil(|py| {
+            self.0
+                .iter()
+                .map(|(k, v)| Ok((k.clone(), Bson(v.clone()).into_object(py, k, &self.1)?)))
+                .collect::<PyResult<Vec<(String, PyObject)>>>()
+        })?;
 
-        DocumentIter {
+        Ok(DocumentIter {
             index: 0,
             len: items_vector.len(),
             document_items: items_vector,
-        }
+        })
     }
 
-    pub fn as_dict(&self) -> PyObject {
-        Python::with_gil(|py| {
-            let dict = PyDict::new(py);
-            for (key, val) in self.0.iter() {
-                let object_value = format_bson(py, val);
-                let _ = dict.set_item(key, object_value);
-            }
-            dict.into()
-        })
+    pub fn as_dict(&self) -> PyResult<PyObject> {
+        Python::with_gil(|py| self.to_dict(py, ""))
+    }
+}
+
+impl Document {
+    fn to_dict(&self, py: Python<'_>, path: &str) -> PyResult<PyObject> {
+        let dict = PyDict::new(py);
+        for (key, val) in self.0.iter() {
+            let object_value = format_bson(py, val, &child_path(path, key), &self.1)?;
+            dict.set_item(key, object_value)?;
+        }
+        Ok(dict.into())
     }
 }
 
-fn format_bson(py: Python<'_>, value: &bson::Bson) -> PyObject {
+fn format_bson(
+    py: Python<'_>,
+    value: &bson::Bson,
+    path: &str,
+    codec: &CodecOptions,
+) -> PyResult<PyObject> {
     match value {
-        bson::Bson::Document(x) => Document(x.clone()).as_dict(),
+        bson::Bson::Document(x) => Document(x.clone(), codec.clone()).to_dict(py, path),
         bson::Bson::Array(x) => {
             let mut formatted_x = Vec::with_capacity(x.len());
-            for value in x {
-                formatted_x.push(format_bson(py, value));
+            for (i, value) in x.iter().enumerate() {
+                formatted_x.push(format_bson(py, value, &child_path(path, i), codec)?);
             }
-            formatted_x.into_py(py)
+            Ok(formatted_x.into_py(py))
         }
-        _ => Bson(value.clone()).into_py(py),
+        _ => Bson(value.clone()).into_object(py, path, codec),
     }
 }
@@ -0,0 +1,213 @@
+// pyo3 0.19's `create_exception!` checks a `cfg(addr_of)` that newer compilers do not know.
+#![allow(unexpected_cfgs)]
+
+use std::collections::HashMap;
+
+use mongodb::{
+    bson,
+    error::{Error, ErrorKind, WriteFailure},
+};
+use pyo3::{create_exception, exceptions::PyValueError, prelude::*, types::PyType};
+
+use super::bson_binding::Bson;
+use super::document_binding::Document;
+use super::options_binding::CodecOptions;
+use super::results_binding::{BulkWriteResult, WriteConcernErrorResult, WriteErrorResult};
+
+create_exception!(
+    ruson.errors,
+    RusonError,
+    PyValueError,
+    "Base class of the errors raised by the driver."
+);
+create_exception!(
+    ruson.errors,
+    OperationFailure,
+    RusonError,
+    "The server rejected an operation."
+);
+create_exception!(
+    ruson.errors,
+    WriteError,
+    OperationFailure,
+    "The server rejected a write."
+);
+create_exception!(
+    ruson.errors,
+    DuplicateKeyError,
+    WriteError,
+    "A write violated a unique index."
+);
+create_exception!(
+    ruson.errors,
+    DocumentValidationError,
+    WriteError,
+    "A written document failed the collection's schema validation."
+);
+create_exception!(
+    ruson.errors,
+    WriteConcernError,
+    OperationFailure,
+    "A write was applied but its write concern was not satisfied."
+);
+create_exception!(
+    ruson.errors,
+    BulkWriteError,
+    OperationFailure,
+    "Some writes of a bulk write or `insert_many` failed."
+);
+create_exception!(
+    ruson.errors,
+    OperationTimeout,
+    OperationFailure,
+    "An operation exceeded its time limit on the server."
+);
+create_exception!(
+    ruson.errors,
+    ConnectionFailure,
+    RusonError,
+    "The connection to the server failed."
+);
+create_exception!(
+    ruson.errors,
+    ServerSelectionTimeout,
+    ConnectionFailure,
+    "No suitable server was found before the server selection timeout."
+);
+create_exception!(
+    ruson.errors,
+    TransactionError,
+    RusonError,
+    "A transaction was used in an invalid state."
+);
+
+const DUPLICATE_KEY_CODES: [i32; 3] = [11000, 11001, 12582];
+const DOCUMENT_VALIDATION_FAILURE: i32 = 121;
+const MAX_TIME_MS_EXPIRED: i32 = 50;
+
+fn write_error_type(py: Python<'_>, code: i32) -> &PyType {
+    match code {
+        c if DUPLICATE_KEY_CODES.contains(&c) => py.get_type::<DuplicateKeyError>(),
+        DOCUMENT_VALIDATION_FAILURE => py.get_type::<DocumentValidationError>(),
+        _ => py.get_type::<WriteError>(),
+    }
+}
+
+fn command_error_type(py: Python<'_>, code: i32) -> &PyType {
+    match code {
+        c if DUPLICATE_KEY_CODES.contains(&c) => py.get_type::<DuplicateKeyError>(),
+        DOCUMENT_VALIDATION_FAILURE => py.get_type::<DocumentValidationError>(),
+        MAX_TIME_MS_EXPIRED => py.get_type::<OperationTimeout>(),
+        _ => py.get_type::<OperationFailure>(),
+    }
+}
+
+/// Builds the exception matching the error's kind. `inserted_ids` holds the ids of the documents
+/// a failed bulk write still inserted, by index, which are read with `codec`.
+pub(crate) fn new_error(
+    py: Python<'_>,
+    e: Error,
+    inserted_ids: HashMap<usize, bson::Bson>,
+    codec: &CodecOptions,
+) -> PyResult<PyErr> {
+    let mut code = None;
+    let mut code_name = None;
+    let mut details = None;
+    let exception_type = match e.kind.as_ref() {
+        ErrorKind::Command(err) => {
+            code = Some(err.code);
+            code_name = Some(err.code_name.clone());
+            command_error_type(py, err.code)
+        }
+        ErrorKind::Write(WriteFailure::WriteError(err)) => {
+            code = Some(err.code);
+            code_name = err.code_name.clone();
+            details = err.details.clone();
+            write_error_type(py, err.code)
+        }
+        ErrorKind::Write(WriteFailure::WriteConcernError(err)) => {
+            code = Some(err.code);
+            code_name = Some(err.code_name.clone());
+            details = err.details.clone();
+            match err.code {
+                MAX_TIME_MS_EXPIRED => py.get_type::<OperationTimeout>(),
+                _ => py.get_type::<WriteConcernError>(),
+            }
+        }
+        ErrorKind::BulkWrite(_) => py.get_type::<BulkWriteError>(),
+        ErrorKind::ServerSelection { .. } => py.get_type::<ServerSelectionTimeout>(),
+        ErrorKind::Io(_)
+        | ErrorKind::ConnectionPoolCleared { .. }
+        | ErrorKind::DnsResolve { .. } => py.get_type::<ConnectionFailure>(),
+        ErrorKind::Transaction { .. } => py.get_type::<TransactionError>(),
+        _ => py.get_type::<RusonError>(),
+    };
+
+    let err = PyErr::from_type(exception_type, e.to_string());
+    let value = err.value(py);
+    let mut labels: Vec<&String> = e.labels().iter().collect();
+    labels.sort();
+    value.setattr("error_labels", labels)?;
+    value.setattr("code", code)?;
+    value.setattr("code_name", code_name)?;
+    value.setattr("details", details.map(|d| Document::from(d).into_py(py)))?;
+
+    if let ErrorKind::BulkWrite(failure) = e.kind.as_ref() {
+        let write_errors: Vec<PyObject> = failure
+            .write_errors
+            .iter()
+            .flatten()
+            .map(|err| WriteErrorResult::new(py, err.clone()).into_py(py))
+            .collect();
+        let write_concern_errors: Vec<PyObject> = failure
+            .write_concern_error
+            .iter()
+            .map(|err| WriteConcernErrorResult::new(py, err.clone()).into_py(py))
+            .collect();
+        let inserted_ids = inserted_ids
+            .into_iter()
+            .map(|(i, id)| Ok((i, Bson(id).into_object(py, &i.to_string(), codec)?)))
+            .collect::<PyResult<HashMap<usize, PyObject>>>()?;
+        value.setattr("write_errors", write_errors)?;
+        value.setattr("write_concern_errors", write_concern_errors)?;
+        value.setattr("inserted_ids", inserted_ids)?;
+        value.setattr("result", py.None())?;
+    }
+    Ok(err)
+}
+
+/// Builds the `BulkWriteError` of a `bulk_write` with failed writes or unsatisfied write concerns.
+/// `result` holds what the bulk write still did.
+pub(crate) fn new_bulk_write_error(py: Python<'_>, result: BulkWriteResult) -> PyResult<PyErr> {
+    let message = match (
+        result.write_errors.first(),
+        result.write_concern_errors.first(),
+    ) {
+        (Some(e), _) => format!(
+            "{} writes of the bulk write failed, the first at index {}: {}",
+            result.write_errors.len(),
+            e.index,
+            e.message
+        ),
+        (None, Some(e)) => format!(
+            "The bulk write did not satisfy its write concern: {}",
+            e.message
+        ),
+        (None, None) => "The bulk write failed".to_owned(),
+    };
+
+    let err = PyErr::from_type(py.get_type::<BulkWriteError>(), message);
+    let value = err.value(py);
+    value.setattr("error_labels", Vec::<String>::new())?;
+    value.setattr("code", py.None())?;
+    value.setattr("code_name", py.None())?;
+    value.setattr("details", py.None())?;
+    value.setattr("write_errors", result.write_errors.clone().into_py(py))?;
+    value.setattr(
+        "write_concern_errors",
+        result.write_concern_errors.clone().into_py(py),
+    )?;
+    value.setattr("inserted_ids", result.inserted_ids.clone())?;
+    value.setattr("result", result.into_py(py))?;
+    Ok(err)
+}
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 
+use mongodb::bson::uuid::UuidRepresentation;
 use pyo3::prelude::*;
 
+use super::bson_binding::EncodeUuids;
 use super::document_binding::Document;
 
 #[pyclass(frozen, get_all, module = "ruson.types")]
@@ -122,3 +124,41 @@ impl IndexModel {
         format!("ruson.types.IndexModel(...)")
     }
 }
+
+impl EncodeUuids for IndexModel {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.keys.encode_uuids(representation)?;
+        if let Some(options) = &mut self.options {
+            options.weigths.encode_uuids(representation)?;
+            options
+                .partial_filter_expression
+                .encode_uuids(representation)?;
+            options.wildcard_projection.encode_uuids(representation)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<mongodb::IndexModel> for IndexModel {
+    fn from(value: mongodb::IndexModel) -> Self {
+        let options = value.options.map(|opts| IndexOptions {
+            name: opts.name,
+            sparse: opts.sparse,
+            unique: opts.unique,
+            default_language: opts.default_language,
+            language_override: opts.language_override,
+            weigths: opts.weights.map(Document::from),
+            bits: opts.bits,
+            max: opts.max,
+            min: opts.min,
+            bucket_size: opts.bucket_size,
+            partial_filter_expression: opts.partial_filter_expression.map(Document::from),
+            wildcard_projection: opts.wildcard_projection.map(Document::from),
+            hidden: opts.hidden,
+        });
+        IndexModel {
+            keys: Document::from(value.keys),
+            options,
+        }
+    }
+}
@@ -1,10 +1,11 @@
 use super::{
     document_binding::Document,
-    index_binding::{IndexModel, IndexOptions},
+    index_binding::IndexModel,
     results_binding::{DocumentResultIterator, IndexResultIterator},
+    utils::to_py_err,
 };
 
-use pyo3::{exceptions, prelude::*};
+use pyo3::prelude::*;
 
 #[pyfunction]
 pub fn document_advance<'a>(
@@ -16,7 +17,7 @@ pub fn document_advance<'a>(
         let result = inner_iterator.advance().await;
         match result {
             Ok(v) => Ok(v),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -30,8 +31,8 @@ pub fn document_current<'a>(
         let inner_iterator = iterator.0.lock().await;
         let result = inner_iterator.deserialize_current();
         match result {
-            Ok(v) => Ok(Document(v)),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(v) => Ok(Document(v, iterator.1.clone())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -43,7 +44,7 @@ pub fn index_advance<'a>(py: Python<'a>, iterator: IndexResultIterator) -> PyRes
         let result = inner_iterator.advance().await;
         match result {
             Ok(v) => Ok(v),
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -54,38 +55,8 @@ pub fn index_current<'a>(py: Python<'a>, iterator: IndexResultIterator) -> PyRes
         let inner_iterator = iterator.0.lock().await;
         let result = inner_iterator.deserialize_current();
         match result {
-            Ok(v) => {
-                let keys = Document(v.keys);
-                let options = match v.options {
-                    Some(opts) => Some(IndexOptions {
-                        name: opts.name,
-                        sparse: opts.sparse,
-                        unique: opts.unique,
-                        default_language: opts.default_language,
-                        language_override: opts.language_override,
-                        weigths: match opts.weights {
-                            Some(w) => Some(Document(w)),
-                            None => None,
-                        },
-                        bits: opts.bits,
-                        max: opts.max,
-                        min: opts.min,
-                        bucket_size: opts.bucket_size,
-                        partial_filter_expression: match opts.partial_filter_expression {
-                            Some(f) => Some(Document(f)),
-                            None => None,
-                        },
-                        wildcard_projection: match opts.wildcard_projection {
-                            Some(p) => Some(Document(p)),
-                            None => None,
-                        },
-                        hidden: opts.hidden,
-                    }),
-                    None => None,
-                };
-                Ok(IndexModel { keys, options })
-            }
-            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+            Ok(v) => Ok(v.into()),
+            Err(e) => Err(to_py_err(e)),
         }
     })
 }
@@ -1,14 +1,18 @@
 use pyo3::prelude::*;
 
 mod bson_binding;
+mod change_stream_binding;
 mod client_binding;
 mod collection_binding;
 mod database_biding;
 mod document_binding;
+mod errors_binding;
 mod index_binding;
 mod iterator_binding;
+mod options_binding;
 mod results_binding;
 mod utils;
+mod write_model_binding;
 
 pub fn client(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     let submodule = PyModule::new(py, "client")?;
@@ -24,7 +28,40 @@ pub fn client(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         client_binding::list_database_names,
         submodule
     )?)?;
+    submodule.add_function(wrap_pyfunction!(client_binding::watch, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::watch_with_checkpoints,
+        submodule
+    )?)?;
     submodule.add_function(wrap_pyfunction!(client_binding::create_session, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::start_transaction,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::commit_transaction,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::abort_transaction,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::with_transaction,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(client_binding::end_session, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(client_binding::session_id, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(client_binding::cluster_time, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(client_binding::operation_time, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::advance_cluster_time,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        client_binding::advance_operation_time,
+        submodule
+    )?)?;
     submodule.add_function(wrap_pyfunction!(client_binding::shutdown, submodule)?)?;
     // let name = format!("ruson.{}.{}", m.name()?, submodule.name()?);
     // py_run!(
@@ -40,11 +77,20 @@ pub fn database(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     let submodule = PyModule::new(py, "database")?;
     submodule.add_class::<database_biding::Database>()?;
     submodule.add_function(wrap_pyfunction!(database_biding::collection, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        database_biding::create_collection,
+        submodule
+    )?)?;
     submodule.add_function(wrap_pyfunction!(database_biding::drop, submodule)?)?;
     submodule.add_function(wrap_pyfunction!(
         database_biding::list_collections,
         submodule
     )?)?;
+    submodule.add_function(wrap_pyfunction!(database_biding::watch, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        database_biding::watch_with_checkpoints,
+        submodule
+    )?)?;
     // let name = format!("ruson.{}.{}", m.name()?, submodule.name()?);
     // py_run!(
     //     py,
@@ -60,6 +106,18 @@ pub fn collection(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     submodule.add_class::<collection_binding::Collection>()?;
     submodule.add_function(wrap_pyfunction!(collection_binding::find_one, submodule)?)?;
     submodule.add_function(wrap_pyfunction!(collection_binding::find_many, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::find_one_and_update,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::find_one_and_replace,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::find_one_and_delete,
+        submodule
+    )?)?;
 
     submodule.add_function(wrap_pyfunction!(collection_binding::insert_one, submodule)?)?;
     submodule.add_function(wrap_pyfunction!(
@@ -67,12 +125,26 @@ pub fn collection(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         submodule
     )?)?;
     submodule.add_function(wrap_pyfunction!(collection_binding::update_one, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::update_many,
+        submodule
+    )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::replace_one,
+        submodule
+    )?)?;
     submodule.add_function(wrap_pyfunction!(collection_binding::delete_one, submodule)?)?;
     submodule.add_function(wrap_pyfunction!(
         collection_binding::delete_many,
         submodule
     )?)?;
+    submodule.add_function(wrap_pyfunction!(collection_binding::bulk_write, submodule)?)?;
     submodule.add_function(wrap_pyfunction!(collection_binding::aggregate, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(collection_binding::watch, submodule)?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::watch_with_checkpoints,
+        submodule
+    )?)?;
     submodule.add_function(wrap_pyfunction!(collection_binding::distinct, submodule)?)?;
     submodule.add_function(wrap_pyfunction!(
         collection_binding::list_indexes,
@@ -90,6 +162,10 @@ pub fn collection(py: Python<'_>, m: &PyModule) -> PyResult<()> {
         collection_binding::count_documents,
         submodule
     )?)?;
+    submodule.add_function(wrap_pyfunction!(
+        collection_binding::estimated_document_count,
+        submodule
+    )?)?;
     submodule.add_function(wrap_pyfunction!(collection_binding::drop, submodule)?)?;
     // let name = format!("ruson.{}.{}", m.name()?, submodule.name()?);
     // py_run!(
@@ -143,6 +219,7 @@ pub fn types(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     submodule.add_class::<bson_binding::ObjectId>()?;
     submodule.add_class::<bson_binding::Regex>()?;
     submodule.add_class::<bson_binding::Timestamp>()?;
+    submodule.add_class::<bson_binding::DatetimeMS>()?;
     submodule.add_class::<bson_binding::Undefined>()?;
     submodule.add_class::<document_binding::Document>()?;
     submodule.add_class::<document_binding::DocumentIter>()?;
@@ -151,9 +228,42 @@ pub fn types(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     submodule.add_class::<results_binding::UpdateResult>()?;
     submodule.add_class::<results_binding::DeleteResult>()?;
     submodule.add_class::<results_binding::CreateIndexesResult>()?;
+    submodule.add_class::<results_binding::BulkWriteResult>()?;
+    submodule.add_class::<results_binding::WriteErrorResult>()?;
+    submodule.add_class::<results_binding::WriteConcernErrorResult>()?;
+    submodule.add_class::<results_binding::DocumentResultIterator>()?;
     submodule.add_class::<results_binding::IndexResultIterator>()?;
+    submodule.add_class::<change_stream_binding::ChangeStream>()?;
+    submodule.add_class::<change_stream_binding::CheckpointedChangeStream>()?;
+    submodule.add_class::<change_stream_binding::ChangeEvent>()?;
+    submodule.add_class::<change_stream_binding::ChangeNamespace>()?;
+    submodule.add_class::<change_stream_binding::UpdateDescription>()?;
+    submodule.add_class::<change_stream_binding::TruncatedArray>()?;
     submodule.add_class::<index_binding::IndexModel>()?;
     submodule.add_class::<index_binding::IndexOptions>()?;
+    submodule.add_class::<options_binding::ReturnDocument>()?;
+    submodule.add_class::<options_binding::CursorType>()?;
+    submodule.add_class::<options_binding::ReadConcern>()?;
+    submodule.add_class::<options_binding::ReadPreference>()?;
+    submodule.add_class::<options_binding::Collation>()?;
+    submodule.add_class::<options_binding::FindOptions>()?;
+    submodule.add_class::<options_binding::WriteConcern>()?;
+    submodule.add_class::<options_binding::AggregateOptions>()?;
+    submodule.add_class::<options_binding::InsertOptions>()?;
+    submodule.add_class::<options_binding::UpdateOptions>()?;
+    submodule.add_class::<options_binding::DeleteOptions>()?;
+    submodule.add_class::<options_binding::CountOptions>()?;
+    submodule.add_class::<options_binding::ChangeStreamOptions>()?;
+    submodule.add_class::<options_binding::TransactionOptions>()?;
+    submodule.add_class::<options_binding::SessionOptions>()?;
+    submodule.add_class::<options_binding::Timeout>()?;
+    submodule.add_class::<options_binding::CodecOptions>()?;
+    submodule.add_class::<write_model_binding::InsertOne>()?;
+    submodule.add_class::<write_model_binding::UpdateOne>()?;
+    submodule.add_class::<write_model_binding::UpdateMany>()?;
+    submodule.add_class::<write_model_binding::ReplaceOne>()?;
+    submodule.add_class::<write_model_binding::DeleteOne>()?;
+    submodule.add_class::<write_model_binding::DeleteMany>()?;
     // let name = format!("ruson.{}.{}", m.name()?, submodule.name()?);
     // py_run!(
     //     py,
@@ -163,3 +273,47 @@ pub fn types(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_submodule(submodule)?;
     Ok(())
 }
+
+pub fn errors(py: Python<'_>, m: &PyModule) -> PyResult<()> {
+    let submodule = PyModule::new(py, "errors")?;
+    submodule.add("RusonError", py.get_type::<errors_binding::RusonError>())?;
+    submodule.add(
+        "OperationFailure",
+        py.get_type::<errors_binding::OperationFailure>(),
+    )?;
+    submodule.add("WriteError", py.get_type::<errors_binding::WriteError>())?;
+    submodule.add(
+        "DuplicateKeyError",
+        py.get_type::<errors_binding::DuplicateKeyError>(),
+    )?;
+    submodule.add(
+        "DocumentValidationError",
+        py.get_type::<errors_binding::DocumentValidationError>(),
+    )?;
+    submodule.add(
+        "WriteConcernError",
+        py.get_type::<errors_binding::WriteConcernError>(),
+    )?;
+    submodule.add(
+        "BulkWriteError",
+        py.get_type::<errors_binding::BulkWriteError>(),
+    )?;
+    submodule.add(
+        "OperationTimeout",
+        py.get_type::<errors_binding::OperationTimeout>(),
+    )?;
+    submodule.add(
+        "ConnectionFailure",
+        py.get_type::<errors_binding::ConnectionFailure>(),
+    )?;
+    submodule.add(
+        "ServerSelectionTimeout",
+        py.get_type::<errors_binding::ServerSelectionTimeout>(),
+    )?;
+    submodule.add(
+        "TransactionError",
+        py.get_type::<errors_binding::TransactionError>(),
+    )?;
+    m.add_submodule(submodule)?;
+    Ok(())
+}
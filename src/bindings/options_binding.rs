@@ -0,0 +1,1230 @@
+use std::{str::FromStr, time::Duration};
+
+use mongodb::{
+    bson::uuid::UuidRepresentation,
+    change_stream::event::ResumeToken,
+    options::{FullDocumentBeforeChangeType, FullDocumentType},
+};
+use pyo3::{exceptions, prelude::*, types::PyTzInfo};
+
+use crate::interface;
+
+use super::bson_binding::{Bson, EncodeUuids};
+use super::document_binding::Document;
+use super::utils::to_py_err;
+
+#[pyclass(module = "ruson.types")]
+#[derive(Clone)]
+pub enum ReturnDocument {
+    /// Return the document before modification.
+    Before,
+
+    /// Return the document after modification.
+    After,
+}
+
+impl From<ReturnDocument> for mongodb::options::ReturnDocument {
+    fn from(value: ReturnDocument) -> Self {
+        match value {
+            ReturnDocument::Before => mongodb::options::ReturnDocument::Before,
+            ReturnDocument::After => mongodb::options::ReturnDocument::After,
+        }
+    }
+}
+
+#[pyclass(module = "ruson.types")]
+#[derive(Clone)]
+pub enum CursorType {
+    /// The cursor is closed once its last result is returned.
+    NonTailable,
+
+    /// The cursor stays open on a capped collection and returns documents inserted after the
+    /// last result.
+    Tailable,
+
+    /// Like `Tailable`, but the server waits up to `max_await_time` for new documents before
+    /// returning an empty batch.
+    TailableAwait,
+}
+
+impl From<CursorType> for mongodb::options::CursorType {
+    fn from(value: CursorType) -> Self {
+        match value {
+            CursorType::NonTailable => mongodb::options::CursorType::NonTailable,
+            CursorType::Tailable => mongodb::options::CursorType::Tailable,
+            CursorType::TailableAwait => mongodb::options::CursorType::TailableAwait,
+        }
+    }
+}
+
+/// The index to use for an operation, either by name or by its keys.
+#[derive(Clone, FromPyObject)]
+pub enum Hint {
+    Name(String),
+    Keys(Document),
+}
+
+impl IntoPy<PyObject> for Hint {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Hint::Name(name) => name.into_py(py),
+            Hint::Keys(keys) => keys.into_py(py),
+        }
+    }
+}
+
+impl From<Hint> for mongodb::options::Hint {
+    fn from(value: Hint) -> Self {
+        match value {
+            Hint::Name(name) => mongodb::options::Hint::Name(name),
+            Hint::Keys(keys) => mongodb::options::Hint::Keys(keys.0),
+        }
+    }
+}
+
+impl EncodeUuids for Hint {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        match self {
+            Hint::Name(_) => Ok(()),
+            Hint::Keys(keys) => keys.encode_uuids(representation),
+        }
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct ReadConcern {
+    /// The read concern level: "local", "majority", "linearizable", "available" or
+    /// "snapshot".
+    pub level: String,
+}
+
+#[pymethods]
+impl ReadConcern {
+    #[new]
+    fn new(level: String) -> Self {
+        Self { level }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ruson.types.ReadConcern(level=\"{}\")", self.level)
+    }
+}
+
+impl From<ReadConcern> for mongodb::options::ReadConcern {
+    fn from(value: ReadConcern) -> Self {
+        match value.level.as_str() {
+            "local" => mongodb::options::ReadConcern::local(),
+            "majority" => mongodb::options::ReadConcern::majority(),
+            "linearizable" => mongodb::options::ReadConcern::linearizable(),
+            "available" => mongodb::options::ReadConcern::available(),
+            "snapshot" => mongodb::options::ReadConcern::snapshot(),
+            _ => mongodb::options::ReadConcern::custom(value.level),
+        }
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct ReadPreference {
+    /// The read preference mode: "primary", "primaryPreferred", "secondary",
+    /// "secondaryPreferred" or "nearest".
+    pub mode: String,
+}
+
+#[pymethods]
+impl ReadPreference {
+    #[new]
+    fn new(mode: String) -> PyResult<Self> {
+        let read_preference = Self { mode };
+        read_preference.to_mongodb()?;
+        Ok(read_preference)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ruson.types.ReadPreference(mode=\"{}\")", self.mode)
+    }
+}
+
+impl ReadPreference {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::ReadPreference> {
+        let options = Default::default();
+        match self.mode.as_str() {
+            "primary" => Ok(mongodb::options::ReadPreference::Primary),
+            "primaryPreferred" => {
+                Ok(mongodb::options::ReadPreference::PrimaryPreferred { options })
+            }
+            "secondary" => Ok(mongodb::options::ReadPreference::Secondary { options }),
+            "secondaryPreferred" => {
+                Ok(mongodb::options::ReadPreference::SecondaryPreferred { options })
+            }
+            "nearest" => Ok(mongodb::options::ReadPreference::Nearest { options }),
+            mode => Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                "Invalid read preference mode: {}",
+                mode
+            ))),
+        }
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct Collation {
+    /// The ICU locale, e.g. "en" or "pt_BR".
+    pub locale: String,
+
+    /// The ICU comparison level, from 1 (base characters only) to 5 (identical).
+    pub strength: Option<u32>,
+
+    /// Whether to include a separate level for case differences.
+    pub case_level: Option<bool>,
+
+    /// The sort order of case differences: "upper", "lower" or "off".
+    pub case_first: Option<String>,
+
+    /// Whether to compare numeric strings as numbers.
+    pub numeric_ordering: Option<bool>,
+
+    /// Whether whitespace and punctuation are base characters: "non-ignorable" or "shifted".
+    pub alternate: Option<String>,
+
+    /// Which characters are ignorable when `alternate` is "shifted": "punct" or "space".
+    pub max_variable: Option<String>,
+
+    /// Whether to check if text requires normalization and to perform it.
+    pub normalization: Option<bool>,
+
+    /// Whether strings with diacritics sort from the back of the string.
+    pub backwards: Option<bool>,
+}
+
+#[pymethods]
+impl Collation {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        locale: String,
+        strength: Option<u32>,
+        case_level: Option<bool>,
+        case_first: Option<String>,
+        numeric_ordering: Option<bool>,
+        alternate: Option<String>,
+        max_variable: Option<String>,
+        normalization: Option<bool>,
+        backwards: Option<bool>,
+    ) -> PyResult<Self> {
+        let collation = Self {
+            locale,
+            strength,
+            case_level,
+            case_first,
+            numeric_ordering,
+            alternate,
+            max_variable,
+            normalization,
+            backwards,
+        };
+        collation.to_mongodb()?;
+        Ok(collation)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ruson.types.Collation(locale=\"{}\", ...)", self.locale)
+    }
+}
+
+impl Collation {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::Collation> {
+        let strength = match self.strength {
+            Some(s) => Some(mongodb::options::CollationStrength::try_from(s).map_err(to_py_err)?),
+            None => None,
+        };
+        let case_first = match &self.case_first {
+            Some(c) => Some(mongodb::options::CollationCaseFirst::from_str(c).map_err(to_py_err)?),
+            None => None,
+        };
+        let alternate = match &self.alternate {
+            Some(a) => Some(mongodb::options::CollationAlternate::from_str(a).map_err(to_py_err)?),
+            None => None,
+        };
+        let max_variable = match &self.max_variable {
+            Some(m) => {
+                Some(mongodb::options::CollationMaxVariable::from_str(m).map_err(to_py_err)?)
+            }
+            None => None,
+        };
+        Ok(mongodb::options::Collation::builder()
+            .locale(self.locale.clone())
+            .strength(strength)
+            .case_level(self.case_level)
+            .case_first(case_first)
+            .numeric_ordering(self.numeric_ordering)
+            .alternate(alternate)
+            .max_variable(max_variable)
+            .normalization(self.normalization)
+            .backwards(self.backwards)
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct FindOptions {
+    /// Allows the server to write temporary files when sorting large result sets. Ignored by
+    /// `find_one`.
+    pub allow_disk_use: Option<bool>,
+
+    /// Returns partial results from a mongos when some shards are unavailable instead of
+    /// failing.
+    pub allow_partial_results: Option<bool>,
+
+    /// The collation to use when comparing strings.
+    pub collation: Option<Collation>,
+
+    /// Tags the query to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+
+    /// The index to use, either its name or its keys.
+    pub hint: Option<Hint>,
+
+    /// The exclusive upper bound for the index used by `hint`.
+    pub max: Option<Document>,
+
+    /// The inclusive lower bound for the index used by `hint`.
+    pub min: Option<Document>,
+
+    /// Prevents the server from closing the cursor after a period of inactivity. Ignored by
+    /// `find_one`.
+    pub no_cursor_timeout: Option<bool>,
+
+    /// The read concern to use instead of the collection's default.
+    pub read_concern: Option<ReadConcern>,
+
+    /// Returns only the index keys in the resulting documents.
+    pub return_key: Option<bool>,
+
+    /// Adds the record identifier to each resulting document.
+    pub show_record_id: Option<bool>,
+
+    /// Variables that can be accessed in the filter with the `$$var` syntax.
+    pub let_vars: Option<Document>,
+
+    /// Keeps the cursor open on a capped collection to follow new documents. Ignored by
+    /// `find_one`.
+    pub cursor_type: Option<CursorType>,
+
+    /// How long a `TailableAwait` cursor waits for new documents on each request, in
+    /// milliseconds. Defaults to the operation timeout. Ignored by `find_one`.
+    pub max_await_time: Option<u64>,
+}
+
+#[pymethods]
+impl FindOptions {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        allow_disk_use: Option<bool>,
+        allow_partial_results: Option<bool>,
+        collation: Option<Collation>,
+        comment: Option<Bson>,
+        hint: Option<Hint>,
+        max: Option<Document>,
+        min: Option<Document>,
+        no_cursor_timeout: Option<bool>,
+        read_concern: Option<ReadConcern>,
+        return_key: Option<bool>,
+        show_record_id: Option<bool>,
+        let_vars: Option<Document>,
+        cursor_type: Option<CursorType>,
+        max_await_time: Option<u64>,
+    ) -> Self {
+        Self {
+            allow_disk_use,
+            allow_partial_results,
+            collation,
+            comment,
+            hint,
+            max,
+            min,
+            no_cursor_timeout,
+            read_concern,
+            return_key,
+            show_record_id,
+            let_vars,
+            cursor_type,
+            max_await_time,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.FindOptions(...)".to_owned()
+    }
+}
+
+impl FindOptions {
+    pub(crate) fn to_find_options(&self) -> PyResult<mongodb::options::FindOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::FindOptions::builder()
+            .allow_disk_use(self.allow_disk_use)
+            .allow_partial_results(self.allow_partial_results)
+            .collation(collation)
+            .comment_bson(self.comment.clone().map(|c| c.0))
+            .hint(self.hint.clone().map(Into::into))
+            .max(self.max.clone().map(|d| d.0))
+            .min(self.min.clone().map(|d| d.0))
+            .no_cursor_timeout(self.no_cursor_timeout)
+            .read_concern(self.read_concern.clone().map(Into::into))
+            .return_key(self.return_key)
+            .show_record_id(self.show_record_id)
+            .let_vars(self.let_vars.clone().map(|d| d.0))
+            .cursor_type(self.cursor_type.clone().map(Into::into))
+            .max_await_time(self.max_await_time.map(Duration::from_millis))
+            .build())
+    }
+
+    pub(crate) fn to_find_one_options(&self) -> PyResult<mongodb::options::FindOneOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::FindOneOptions::builder()
+            .allow_partial_results(self.allow_partial_results)
+            .collation(collation)
+            .comment_bson(self.comment.clone().map(|c| c.0))
+            .hint(self.hint.clone().map(Into::into))
+            .max(self.max.clone().map(|d| d.0))
+            .min(self.min.clone().map(|d| d.0))
+            .read_concern(self.read_concern.clone().map(Into::into))
+            .return_key(self.return_key)
+            .show_record_id(self.show_record_id)
+            .let_vars(self.let_vars.clone().map(|d| d.0))
+            .build())
+    }
+}
+
+impl EncodeUuids for FindOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.comment.encode_uuids(representation)?;
+        self.hint.encode_uuids(representation)?;
+        self.max.encode_uuids(representation)?;
+        self.min.encode_uuids(representation)?;
+        self.let_vars.encode_uuids(representation)
+    }
+}
+
+/// The acknowledgment requested by a write concern, either a number of nodes or a tag such as
+/// "majority".
+#[derive(Clone, FromPyObject)]
+pub enum Acknowledgment {
+    Nodes(u32),
+    Tag(String),
+}
+
+impl IntoPy<PyObject> for Acknowledgment {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            Acknowledgment::Nodes(nodes) => nodes.into_py(py),
+            Acknowledgment::Tag(tag) => tag.into_py(py),
+        }
+    }
+}
+
+impl From<Acknowledgment> for mongodb::options::Acknowledgment {
+    fn from(value: Acknowledgment) -> Self {
+        match value {
+            Acknowledgment::Nodes(nodes) => nodes.into(),
+            Acknowledgment::Tag(tag) => tag.into(),
+        }
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct WriteConcern {
+    /// The number of nodes that must acknowledge the write, or a tag such as "majority".
+    pub w: Option<Acknowledgment>,
+
+    /// How long to wait for the acknowledgment, in milliseconds.
+    pub w_timeout: Option<u64>,
+
+    /// Whether the write must be committed to the on-disk journal before it is acknowledged.
+    pub journal: Option<bool>,
+}
+
+#[pymethods]
+impl WriteConcern {
+    #[new]
+    fn new(w: Option<Acknowledgment>, w_timeout: Option<u64>, journal: Option<bool>) -> Self {
+        Self {
+            w,
+            w_timeout,
+            journal,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.WriteConcern(...)".to_owned()
+    }
+}
+
+impl From<WriteConcern> for mongodb::options::WriteConcern {
+    fn from(value: WriteConcern) -> Self {
+        mongodb::options::WriteConcern::builder()
+            .w(value.w.map(Into::into))
+            .w_timeout(value.w_timeout.map(Duration::from_millis))
+            .journal(value.journal)
+            .build()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct AggregateOptions {
+    /// Allows the server to write temporary files for stages that exceed the memory limit.
+    pub allow_disk_use: Option<bool>,
+
+    /// Skips document validation for `$out` and `$merge` stages.
+    pub bypass_document_validation: Option<bool>,
+
+    /// The collation to use when comparing strings.
+    pub collation: Option<Collation>,
+
+    /// Tags the pipeline to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+
+    /// The index to use, either its name or its keys.
+    pub hint: Option<Hint>,
+
+    /// The read concern to use instead of the collection's default.
+    pub read_concern: Option<ReadConcern>,
+
+    /// The write concern for `$out` and `$merge` stages.
+    pub write_concern: Option<WriteConcern>,
+
+    /// Variables that can be accessed in the pipeline with the `$$var` syntax.
+    pub let_vars: Option<Document>,
+}
+
+#[pymethods]
+impl AggregateOptions {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        allow_disk_use: Option<bool>,
+        bypass_document_validation: Option<bool>,
+        collation: Option<Collation>,
+        comment: Option<Bson>,
+        hint: Option<Hint>,
+        read_concern: Option<ReadConcern>,
+        write_concern: Option<WriteConcern>,
+        let_vars: Option<Document>,
+    ) -> Self {
+        Self {
+            allow_disk_use,
+            bypass_document_validation,
+            collation,
+            comment,
+            hint,
+            read_concern,
+            write_concern,
+            let_vars,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.AggregateOptions(...)".to_owned()
+    }
+}
+
+impl EncodeUuids for AggregateOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.comment.encode_uuids(representation)?;
+        self.hint.encode_uuids(representation)?;
+        self.let_vars.encode_uuids(representation)
+    }
+}
+
+impl AggregateOptions {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::AggregateOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::AggregateOptions::builder()
+            .allow_disk_use(self.allow_disk_use)
+            .bypass_document_validation(self.bypass_document_validation)
+            .collation(collation)
+            .comment_bson(self.comment.clone().map(|c| c.0))
+            .hint(self.hint.clone().map(Into::into))
+            .read_concern(self.read_concern.clone().map(Into::into))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .let_vars(self.let_vars.clone().map(|d| d.0))
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct InsertOptions {
+    /// Whether to stop at the first failed insert or keep inserting the remaining documents.
+    /// Ignored by `insert_one`.
+    pub ordered: Option<bool>,
+
+    /// Skips the collection's document validation.
+    pub bypass_document_validation: Option<bool>,
+
+    /// Tags the operation to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+
+    /// The write concern to use instead of the collection's default.
+    pub write_concern: Option<WriteConcern>,
+}
+
+#[pymethods]
+impl InsertOptions {
+    #[new]
+    fn new(
+        ordered: Option<bool>,
+        bypass_document_validation: Option<bool>,
+        comment: Option<Bson>,
+        write_concern: Option<WriteConcern>,
+    ) -> Self {
+        Self {
+            ordered,
+            bypass_document_validation,
+            comment,
+            write_concern,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.InsertOptions(...)".to_owned()
+    }
+}
+
+impl EncodeUuids for InsertOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.comment.encode_uuids(representation)
+    }
+}
+
+impl InsertOptions {
+    pub(crate) fn to_insert_one_options(&self) -> mongodb::options::InsertOneOptions {
+        mongodb::options::InsertOneOptions::builder()
+            .bypass_document_validation(self.bypass_document_validation)
+            .comment(self.comment.clone().map(|c| c.0))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .build()
+    }
+
+    pub(crate) fn to_insert_many_options(&self) -> mongodb::options::InsertManyOptions {
+        mongodb::options::InsertManyOptions::builder()
+            .ordered(self.ordered)
+            .bypass_document_validation(self.bypass_document_validation)
+            .comment(self.comment.clone().map(|c| c.0))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .build()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct UpdateOptions {
+    /// The index to use, either its name or its keys.
+    pub hint: Option<Hint>,
+
+    /// The collation to use when comparing strings.
+    pub collation: Option<Collation>,
+
+    /// Variables that can be accessed in the filter and update with the `$$var` syntax.
+    pub let_vars: Option<Document>,
+
+    /// Tags the operation to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+
+    /// The write concern to use instead of the collection's default.
+    pub write_concern: Option<WriteConcern>,
+}
+
+#[pymethods]
+impl UpdateOptions {
+    #[new]
+    fn new(
+        hint: Option<Hint>,
+        collation: Option<Collation>,
+        let_vars: Option<Document>,
+        comment: Option<Bson>,
+        write_concern: Option<WriteConcern>,
+    ) -> Self {
+        Self {
+            hint,
+            collation,
+            let_vars,
+            comment,
+            write_concern,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.UpdateOptions(...)".to_owned()
+    }
+}
+
+impl EncodeUuids for UpdateOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.hint.encode_uuids(representation)?;
+        self.let_vars.encode_uuids(representation)?;
+        self.comment.encode_uuids(representation)
+    }
+}
+
+impl UpdateOptions {
+    pub(crate) fn to_update_options(&self) -> PyResult<mongodb::options::UpdateOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::UpdateOptions::builder()
+            .hint(self.hint.clone().map(Into::into))
+            .collation(collation)
+            .let_vars(self.let_vars.clone().map(|d| d.0))
+            .comment(self.comment.clone().map(|c| c.0))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .build())
+    }
+
+    pub(crate) fn to_replace_options(&self) -> PyResult<mongodb::options::ReplaceOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::ReplaceOptions::builder()
+            .hint(self.hint.clone().map(Into::into))
+            .collation(collation)
+            .let_vars(self.let_vars.clone().map(|d| d.0))
+            .comment(self.comment.clone().map(|c| c.0))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct DeleteOptions {
+    /// The index to use, either its name or its keys.
+    pub hint: Option<Hint>,
+
+    /// The collation to use when comparing strings.
+    pub collation: Option<Collation>,
+
+    /// Variables that can be accessed in the filter with the `$$var` syntax.
+    pub let_vars: Option<Document>,
+
+    /// Tags the operation to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+
+    /// The write concern to use instead of the collection's default.
+    pub write_concern: Option<WriteConcern>,
+}
+
+#[pymethods]
+impl DeleteOptions {
+    #[new]
+    fn new(
+        hint: Option<Hint>,
+        collation: Option<Collation>,
+        let_vars: Option<Document>,
+        comment: Option<Bson>,
+        write_concern: Option<WriteConcern>,
+    ) -> Self {
+        Self {
+            hint,
+            collation,
+            let_vars,
+            comment,
+            write_concern,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.DeleteOptions(...)".to_owned()
+    }
+}
+
+impl EncodeUuids for DeleteOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.hint.encode_uuids(representation)?;
+        self.let_vars.encode_uuids(representation)?;
+        self.comment.encode_uuids(representation)
+    }
+}
+
+impl DeleteOptions {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::DeleteOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::DeleteOptions::builder()
+            .hint(self.hint.clone().map(Into::into))
+            .collation(collation)
+            .let_vars(self.let_vars.clone().map(|d| d.0))
+            .comment(self.comment.clone().map(|c| c.0))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct CountOptions {
+    /// The index to use, either its name or its keys.
+    pub hint: Option<Hint>,
+
+    /// The collation to use when comparing strings.
+    pub collation: Option<Collation>,
+
+    /// The read concern to use instead of the collection's default.
+    pub read_concern: Option<ReadConcern>,
+
+    /// Tags the operation to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+}
+
+#[pymethods]
+impl CountOptions {
+    #[new]
+    fn new(
+        hint: Option<Hint>,
+        collation: Option<Collation>,
+        read_concern: Option<ReadConcern>,
+        comment: Option<Bson>,
+    ) -> Self {
+        Self {
+            hint,
+            collation,
+            read_concern,
+            comment,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.CountOptions(...)".to_owned()
+    }
+}
+
+impl EncodeUuids for CountOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.hint.encode_uuids(representation)?;
+        self.comment.encode_uuids(representation)
+    }
+}
+
+impl CountOptions {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::CountOptions> {
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::CountOptions::builder()
+            .hint(self.hint.clone().map(Into::into))
+            .collation(collation)
+            .read_concern(self.read_concern.clone().map(Into::into))
+            .comment(self.comment.clone().map(|c| c.0))
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct ChangeStreamOptions {
+    /// When update events include the whole document: "default", "updateLookup",
+    /// "whenAvailable" or "required".
+    pub full_document: Option<String>,
+
+    /// When events include the document as it was before the change: "off", "whenAvailable" or
+    /// "required".
+    pub full_document_before_change: Option<String>,
+
+    /// Starts the stream after the event with this resume token.
+    pub resume_after: Option<Document>,
+
+    /// Like `resume_after`, but can also start after an invalidate event.
+    pub start_after: Option<Document>,
+
+    /// Starts the stream at this operation time. Must be a `Timestamp`.
+    pub(crate) start_at_operation_time: Option<Bson>,
+
+    /// How long the server waits for new events before returning an empty batch, in
+    /// milliseconds.
+    pub max_await_time: Option<u64>,
+
+    /// How many events the server returns per batch.
+    pub batch_size: Option<u32>,
+
+    /// The collation to use when comparing strings.
+    pub collation: Option<Collation>,
+
+    /// The read concern to use instead of the target's default.
+    pub read_concern: Option<ReadConcern>,
+
+    /// Tags the stream to help trace it through the profiler, currentOp and logs.
+    pub(crate) comment: Option<Bson>,
+}
+
+#[pymethods]
+impl ChangeStreamOptions {
+    #[new]
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        full_document: Option<String>,
+        full_document_before_change: Option<String>,
+        resume_after: Option<Document>,
+        start_after: Option<Document>,
+        start_at_operation_time: Option<Bson>,
+        max_await_time: Option<u64>,
+        batch_size: Option<u32>,
+        collation: Option<Collation>,
+        read_concern: Option<ReadConcern>,
+        comment: Option<Bson>,
+    ) -> PyResult<Self> {
+        let options = Self {
+            full_document,
+            full_document_before_change,
+            resume_after,
+            start_after,
+            start_at_operation_time,
+            max_await_time,
+            batch_size,
+            collation,
+            read_concern,
+            comment,
+        };
+        options.to_mongodb()?;
+        Ok(options)
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.ChangeStreamOptions(...)".to_owned()
+    }
+}
+
+fn to_resume_token(token: &Option<Document>) -> PyResult<Option<ResumeToken>> {
+    match token {
+        Some(t) => match mongodb::bson::from_bson(mongodb::bson::Bson::Document(t.0.clone())) {
+            Ok(v) => Ok(Some(v)),
+            Err(e) => Err(PyErr::new::<exceptions::PyValueError, _>(e.to_string())),
+        },
+        None => Ok(None),
+    }
+}
+
+impl EncodeUuids for ChangeStreamOptions {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        self.comment.encode_uuids(representation)
+    }
+}
+
+impl ChangeStreamOptions {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::ChangeStreamOptions> {
+        let full_document = match self.full_document.as_deref() {
+            None | Some("default") => None,
+            Some("updateLookup") => Some(FullDocumentType::UpdateLookup),
+            Some("whenAvailable") => Some(FullDocumentType::WhenAvailable),
+            Some("required") => Some(FullDocumentType::Required),
+            Some(mode) => {
+                return Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                    "Invalid full document mode: {}",
+                    mode
+                )))
+            }
+        };
+        let full_document_before_change = match self.full_document_before_change.as_deref() {
+            None => None,
+            Some("off") => Some(FullDocumentBeforeChangeType::Off),
+            Some("whenAvailable") => Some(FullDocumentBeforeChangeType::WhenAvailable),
+            Some("required") => Some(FullDocumentBeforeChangeType::Required),
+            Some(mode) => {
+                return Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                    "Invalid full document before change mode: {}",
+                    mode
+                )))
+            }
+        };
+        let start_at_operation_time = match &self.start_at_operation_time {
+            Some(Bson(mongodb::bson::Bson::Timestamp(ts))) => Some(*ts),
+            Some(_) => {
+                return Err(PyErr::new::<exceptions::PyTypeError, _>(
+                    "Start at operation time must be a Timestamp",
+                ))
+            }
+            None => None,
+        };
+        let collation = match &self.collation {
+            Some(c) => Some(c.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::ChangeStreamOptions::builder()
+            .full_document(full_document)
+            .full_document_before_change(full_document_before_change)
+            .resume_after(to_resume_token(&self.resume_after)?)
+            .start_after(to_resume_token(&self.start_after)?)
+            .start_at_operation_time(start_at_operation_time)
+            .max_await_time(self.max_await_time.map(Duration::from_millis))
+            .batch_size(self.batch_size)
+            .collation(collation)
+            .read_concern(self.read_concern.clone().map(Into::into))
+            .comment(self.comment.clone().map(|c| c.0))
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct TransactionOptions {
+    /// The read concern for every read in the transaction.
+    pub read_concern: Option<ReadConcern>,
+
+    /// The write concern used to commit or abort the transaction.
+    pub write_concern: Option<WriteConcern>,
+
+    /// The read preference for every read in the transaction. Transactions must read from the
+    /// primary.
+    pub read_preference: Option<ReadPreference>,
+
+    /// How long a single commit may run on the server, in milliseconds.
+    pub max_commit_time: Option<u64>,
+}
+
+#[pymethods]
+impl TransactionOptions {
+    #[new]
+    fn new(
+        read_concern: Option<ReadConcern>,
+        write_concern: Option<WriteConcern>,
+        read_preference: Option<ReadPreference>,
+        max_commit_time: Option<u64>,
+    ) -> Self {
+        Self {
+            read_concern,
+            write_concern,
+            read_preference,
+            max_commit_time,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.TransactionOptions(...)".to_owned()
+    }
+}
+
+impl TransactionOptions {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::TransactionOptions> {
+        let selection_criteria = match &self.read_preference {
+            Some(r) => Some(mongodb::options::SelectionCriteria::ReadPreference(
+                r.to_mongodb()?,
+            )),
+            None => None,
+        };
+        Ok(mongodb::options::TransactionOptions::builder()
+            .read_concern(self.read_concern.clone().map(Into::into))
+            .write_concern(self.write_concern.clone().map(Into::into))
+            .selection_criteria(selection_criteria)
+            .max_commit_time(self.max_commit_time.map(Duration::from_millis))
+            .build())
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct SessionOptions {
+    /// Makes every operation in the session observe the ones before it. Defaults to true unless
+    /// `snapshot` is set.
+    pub causal_consistency: Option<bool>,
+
+    /// Makes every read in the session see the same point-in-time snapshot. Cannot be combined
+    /// with causal consistency or transactions.
+    pub snapshot: Option<bool>,
+
+    /// The options used by transactions started without options of their own.
+    pub default_transaction_options: Option<TransactionOptions>,
+}
+
+#[pymethods]
+impl SessionOptions {
+    #[new]
+    fn new(
+        causal_consistency: Option<bool>,
+        snapshot: Option<bool>,
+        default_transaction_options: Option<TransactionOptions>,
+    ) -> Self {
+        Self {
+            causal_consistency,
+            snapshot,
+            default_transaction_options,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.SessionOptions(...)".to_owned()
+    }
+}
+
+impl SessionOptions {
+    pub(crate) fn to_mongodb(&self) -> PyResult<mongodb::options::SessionOptions> {
+        let default_transaction_options = match &self.default_transaction_options {
+            Some(o) => Some(o.to_mongodb()?),
+            None => None,
+        };
+        Ok(mongodb::options::SessionOptions::builder()
+            .causal_consistency(self.causal_consistency)
+            .snapshot(self.snapshot)
+            .default_transaction_options(default_transaction_options)
+            .build())
+    }
+}
+
+#[pyclass(frozen, module = "ruson.types")]
+#[derive(Clone)]
+pub struct Timeout(pub(crate) interface::Timeout);
+
+#[pymethods]
+impl Timeout {
+    #[new]
+    fn new(milliseconds: u64) -> Self {
+        Self(interface::Timeout::Milliseconds(milliseconds))
+    }
+
+    /// A timeout that lets operations run until they complete.
+    #[staticmethod]
+    fn unbounded() -> Self {
+        Self(interface::Timeout::Unbounded)
+    }
+
+    /// The time limit in milliseconds, or `None` when unbounded.
+    #[getter]
+    fn milliseconds(&self) -> Option<u64> {
+        match self.0 {
+            interface::Timeout::Milliseconds(ms) => Some(ms),
+            interface::Timeout::Unbounded => None,
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        match self.0 {
+            interface::Timeout::Milliseconds(ms) => format!("ruson.types.Timeout({})", ms),
+            interface::Timeout::Unbounded => "ruson.types.Timeout.unbounded()".to_owned(),
+        }
+    }
+}
+
+/// A timeout given either as a whole number of seconds or as a `Timeout`, which has millisecond
+/// granularity.
+#[derive(FromPyObject)]
+pub enum TimeoutValue {
+    Seconds(u64),
+    Timeout(Timeout),
+}
+
+impl From<TimeoutValue> for interface::Timeout {
+    fn from(value: TimeoutValue) -> Self {
+        match value {
+            TimeoutValue::Seconds(s) => interface::Timeout::Milliseconds(s.saturating_mul(1000)),
+            TimeoutValue::Timeout(timeout) => timeout.0,
+        }
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct CodecOptions {
+    /// The time zone of the datetimes read from the database. Defaults to UTC.
+    pub tzinfo: Option<PyObject>,
+
+    /// How datetimes are read: "datetime" raises `OverflowError` for values outside the range of
+    /// `datetime`, "clamp" returns `datetime.min` or `datetime.max` for them, "auto" returns them
+    /// as `DatetimeMS`, and "ms" returns every datetime as `DatetimeMS`.
+    pub datetime_conversion: String,
+
+    /// How `uuid.UUID` values are stored: "standard" uses binary subtype 4, while
+    /// "python_legacy", "java_legacy" and "csharp_legacy" use subtype 3 in the byte order of the
+    /// older drivers. With "unspecified", storing a `uuid.UUID` fails and every UUID binary is
+    /// read as a `Binary`.
+    pub uuid_representation: String,
+}
+
+impl Default for CodecOptions {
+    fn default() -> Self {
+        Self {
+            tzinfo: None,
+            datetime_conversion: "datetime".to_owned(),
+            uuid_representation: "standard".to_owned(),
+        }
+    }
+}
+
+impl CodecOptions {
+    /// Parses the name of a UUID representation, which is `None` for "unspecified".
+    pub(crate) fn parse_uuid_representation(value: &str) -> PyResult<Option<UuidRepresentation>> {
+        match value {
+            "standard" => Ok(Some(UuidRepresentation::Standard)),
+            "python_legacy" => Ok(Some(UuidRepresentation::PythonLegacy)),
+            "java_legacy" => Ok(Some(UuidRepresentation::JavaLegacy)),
+            "csharp_legacy" => Ok(Some(UuidRepresentation::CSharpLegacy)),
+            "unspecified" => Ok(None),
+            _ => Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                "Invalid UUID representation: {}",
+                value
+            ))),
+        }
+    }
+
+    pub(crate) fn to_uuid_representation(&self) -> Option<UuidRepresentation> {
+        Self::parse_uuid_representation(&self.uuid_representation).unwrap_or_default()
+    }
+
+    /// Encodes the `uuid.UUID` values of a value sent to the server in the UUID representation.
+    /// Fails when the representation is unspecified.
+    pub(crate) fn encode(&self, value: &mut impl EncodeUuids) -> PyResult<()> {
+        value.encode_uuids(self.to_uuid_representation())
+    }
+}
+
+#[pymethods]
+impl CodecOptions {
+    #[new]
+    fn new(
+        tzinfo: Option<&PyAny>,
+        datetime_conversion: Option<String>,
+        uuid_representation: Option<String>,
+    ) -> PyResult<Self> {
+        if let Some(tz) = tzinfo {
+            if !tz.is_instance_of::<PyTzInfo>() {
+                return Err(PyErr::new::<exceptions::PyTypeError, _>(
+                    "Time zone must be a tzinfo",
+                ));
+            }
+        }
+        let datetime_conversion = datetime_conversion.unwrap_or_else(|| "datetime".to_owned());
+        if !["datetime", "clamp", "auto", "ms"].contains(&datetime_conversion.as_str()) {
+            return Err(PyErr::new::<exceptions::PyValueError, _>(format!(
+                "Invalid datetime conversion: {}",
+                datetime_conversion
+            )));
+        }
+        let uuid_representation = uuid_representation.unwrap_or_else(|| "standard".to_owned());
+        Self::parse_uuid_representation(&uuid_representation)?;
+        Ok(Self {
+            tzinfo: tzinfo.map(Into::into),
+            datetime_conversion,
+            uuid_representation,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.CodecOptions(...)".to_owned()
+    }
+}
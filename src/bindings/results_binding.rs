@@ -1,11 +1,23 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use mongodb::{bson::Document, IndexModel};
-use pyo3::prelude::*;
+use mongodb::{
+    bson::Document,
+    error::{BulkWriteError, WriteConcernError},
+    IndexModel,
+};
+use pyo3::{exceptions, prelude::*};
 use tokio::sync::Mutex;
 
 use crate::interface;
 
+use super::document_binding;
+use super::index_binding;
+use super::options_binding::CodecOptions;
+use super::utils::to_py_err;
+
+/// How many bytes of BSON `to_list` collects when no `max_bytes` is given.
+const TO_LIST_MAX_BYTES: usize = 256 * 1024 * 1024;
+
 #[pyclass(frozen, get_all)]
 #[derive(Clone)]
 pub struct InsertOneResult {
@@ -38,12 +50,312 @@ pub struct CreateIndexesResult {
     pub index_names: Vec<String>,
 }
 
+#[pyclass(frozen, get_all)]
+#[derive(Clone)]
+pub struct WriteErrorResult {
+    pub index: usize,
+    pub code: i32,
+    pub code_name: Option<String>,
+    pub message: String,
+    pub details: Option<PyObject>,
+}
+
+#[pyclass(frozen, get_all)]
+#[derive(Clone)]
+pub struct WriteConcernErrorResult {
+    pub code: i32,
+    pub code_name: String,
+    pub message: String,
+    pub details: Option<PyObject>,
+}
+
+impl WriteErrorResult {
+    pub(crate) fn new(py: Python<'_>, e: BulkWriteError) -> Self {
+        Self {
+            index: e.index,
+            code: e.code,
+            code_name: e.code_name,
+            message: e.message,
+            details: e
+                .details
+                .map(|d| document_binding::Document::from(d).into_py(py)),
+        }
+    }
+}
+
+impl WriteConcernErrorResult {
+    pub(crate) fn new(py: Python<'_>, e: WriteConcernError) -> Self {
+        Self {
+            code: e.code,
+            code_name: e.code_name,
+            message: e.message,
+            details: e
+                .details
+                .map(|d| document_binding::Document::from(d).into_py(py)),
+        }
+    }
+}
+
+#[pyclass(frozen, get_all)]
+#[derive(Clone)]
+pub struct BulkWriteResult {
+    pub inserted_count: u64,
+    pub matched_count: u64,
+    pub modified_count: u64,
+    pub deleted_count: u64,
+    pub upserted_count: u64,
+    pub inserted_ids: HashMap<usize, PyObject>,
+    pub upserted_ids: HashMap<usize, PyObject>,
+    pub write_errors: Vec<WriteErrorResult>,
+    pub write_concern_errors: Vec<WriteConcernErrorResult>,
+}
+
+/// A cursor, with its id and closer kept outside of the lock that operations on it hold.
 #[pyclass(frozen)]
-#[repr(transparent)]
 #[derive(Clone)]
-pub struct DocumentResultIterator(pub(crate) Arc<Mutex<interface::ResultIterator<Document>>>);
+pub struct DocumentResultIterator(
+    pub(crate) Arc<Mutex<interface::ResultIterator<Document>>>,
+    pub(crate) CodecOptions,
+    i64,
+    interface::Closer,
+);
+
+impl DocumentResultIterator {
+    pub(crate) fn new(iterator: interface::ResultIterator<Document>, codec: CodecOptions) -> Self {
+        let id = iterator.id();
+        let closer = iterator.closer();
+        DocumentResultIterator(Arc::new(Mutex::new(iterator)), codec, id, closer)
+    }
+}
 
 #[pyclass(frozen)]
-#[repr(transparent)]
 #[derive(Clone)]
-pub struct IndexResultIterator(pub(crate) Arc<Mutex<interface::ResultIterator<IndexModel>>>);
+pub struct IndexResultIterator(
+    pub(crate) Arc<Mutex<interface::ResultIterator<IndexModel>>>,
+    i64,
+    interface::Closer,
+);
+
+impl From<interface::ResultIterator<IndexModel>> for IndexResultIterator {
+    fn from(iterator: interface::ResultIterator<IndexModel>) -> Self {
+        let id = iterator.id();
+        let closer = iterator.closer();
+        IndexResultIterator(Arc::new(Mutex::new(iterator)), id, closer)
+    }
+}
+
+#[pymethods]
+impl DocumentResultIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// The id of the cursor on the server, which is 0 when the first batch held every result.
+    #[getter]
+    fn id(&self) -> i64 {
+        self.2
+    }
+
+    /// Whether the cursor may still return documents. It is false once the cursor is exhausted
+    /// or closed.
+    #[getter]
+    fn alive(&self) -> bool {
+        // A locked cursor is in the middle of an operation, so it is still alive.
+        !self.3.is_closed() && self.0.try_lock().map_or(true, |i| i.alive())
+    }
+
+    /// Whether another document is available. May fetch the next batch from the server.
+    fn has_next<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let iterator = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, bool>(py, async move {
+            let result = iterator.lock().await.has_next().await;
+            match result {
+                Ok(v) => Ok(v),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })
+    }
+
+    /// Kills the cursor on the server right away instead of when the cursor is garbage collected.
+    /// An operation waiting on the cursor, such as a tailable cursor awaiting data, stops first.
+    fn close<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        self.3.close();
+        let iterator = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+            iterator.lock().await.close();
+            Ok(())
+        })
+    }
+
+    fn __aenter__<'a>(slf: PyRef<'a, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let iterator: PyObject = slf.into_py(py);
+        pyo3_asyncio::tokio::future_into_py::<_, PyObject>(py, async move { Ok(iterator) })
+    }
+
+    fn __aexit__<'a>(
+        &self,
+        py: Python<'a>,
+        _exc_type: &PyAny,
+        _exc_value: &PyAny,
+        _traceback: &PyAny,
+    ) -> PyResult<&'a PyAny> {
+        self.close(py)
+    }
+
+    /// Advances the cursor and returns the next document in a single future. Raises
+    /// `StopAsyncIteration` once the cursor is exhausted.
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let iterator = self.0.clone();
+        let codec = self.1.clone();
+        let future = pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut inner_iterator = iterator.lock().await;
+            match inner_iterator.advance().await {
+                Ok(true) => match inner_iterator.deserialize_current() {
+                    Ok(v) => Ok(document_binding::Document(v, codec)),
+                    Err(e) => Err(to_py_err(e)),
+                },
+                Ok(false) => Err(PyErr::new::<exceptions::PyStopAsyncIteration, _>(())),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })?;
+        Ok(Some(future.into()))
+    }
+
+    /// Returns up to `n` documents from the cursor in a single list. The list is shorter than `n`
+    /// only when the cursor is exhausted.
+    fn next_batch<'a>(&self, py: Python<'a>, n: usize) -> PyResult<&'a PyAny> {
+        let iterator = self.0.clone();
+        let codec = self.1.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, Vec<document_binding::Document>>(py, async move {
+            let mut inner_iterator = iterator.lock().await;
+            let mut batch = Vec::new();
+            while batch.len() < n {
+                match inner_iterator.advance().await {
+                    Ok(true) => (),
+                    Ok(false) => break,
+                    Err(e) => return Err(to_py_err(e)),
+                }
+                match inner_iterator.deserialize_current() {
+                    Ok(v) => batch.push(document_binding::Document(v, codec.clone())),
+                    Err(e) => return Err(to_py_err(e)),
+                }
+            }
+            Ok(batch)
+        })
+    }
+
+    /// Returns the remaining documents, or the first `max_length` of them, in a single list.
+    /// Raises `MemoryError` once their BSON adds up to more than `max_bytes`, which defaults to
+    /// 256 MiB.
+    fn to_list<'a>(
+        &self,
+        py: Python<'a>,
+        max_length: Option<usize>,
+        max_bytes: Option<usize>,
+    ) -> PyResult<&'a PyAny> {
+        let iterator = self.0.clone();
+        let codec = self.1.clone();
+        let max_bytes = max_bytes.unwrap_or(TO_LIST_MAX_BYTES);
+        pyo3_asyncio::tokio::future_into_py::<_, Vec<document_binding::Document>>(py, async move {
+            let mut inner_iterator = iterator.lock().await;
+            let mut list = Vec::new();
+            let mut size = 0;
+            while max_length.is_none_or(|m| list.len() < m) {
+                match inner_iterator.advance().await {
+                    Ok(true) => (),
+                    Ok(false) => break,
+                    Err(e) => return Err(to_py_err(e)),
+                }
+                size += inner_iterator.current_size();
+                if size > max_bytes {
+                    return Err(PyErr::new::<exceptions::PyMemoryError, _>(format!(
+                        "Cursor results exceed the limit of {} bytes",
+                        max_bytes
+                    )));
+                }
+                match inner_iterator.deserialize_current() {
+                    Ok(v) => list.push(document_binding::Document(v, codec.clone())),
+                    Err(e) => return Err(to_py_err(e)),
+                }
+            }
+            Ok(list)
+        })
+    }
+}
+
+#[pymethods]
+impl IndexResultIterator {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    /// The id of the cursor on the server, which is 0 when the first batch held every index.
+    #[getter]
+    fn id(&self) -> i64 {
+        self.1
+    }
+
+    /// Whether the cursor may still return documents. It is false once the cursor is exhausted
+    /// or closed.
+    #[getter]
+    fn alive(&self) -> bool {
+        // A locked cursor is in the middle of an operation, so it is still alive.
+        !self.2.is_closed() && self.0.try_lock().map_or(true, |i| i.alive())
+    }
+
+    /// Whether another index is available. May fetch the next batch from the server.
+    fn has_next<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let iterator = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, bool>(py, async move {
+            let result = iterator.lock().await.has_next().await;
+            match result {
+                Ok(v) => Ok(v),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })
+    }
+
+    /// Kills the cursor on the server right away instead of when the cursor is garbage collected.
+    /// An operation waiting on the cursor, such as a tailable cursor awaiting data, stops first.
+    fn close<'a>(&self, py: Python<'a>) -> PyResult<&'a PyAny> {
+        self.2.close();
+        let iterator = self.0.clone();
+        pyo3_asyncio::tokio::future_into_py::<_, ()>(py, async move {
+            iterator.lock().await.close();
+            Ok(())
+        })
+    }
+
+    fn __aenter__<'a>(slf: PyRef<'a, Self>, py: Python<'a>) -> PyResult<&'a PyAny> {
+        let iterator: PyObject = slf.into_py(py);
+        pyo3_asyncio::tokio::future_into_py::<_, PyObject>(py, async move { Ok(iterator) })
+    }
+
+    fn __aexit__<'a>(
+        &self,
+        py: Python<'a>,
+        _exc_type: &PyAny,
+        _exc_value: &PyAny,
+        _traceback: &PyAny,
+    ) -> PyResult<&'a PyAny> {
+        self.close(py)
+    }
+
+    /// Advances the cursor and returns the next index in a single future. Raises
+    /// `StopAsyncIteration` once the cursor is exhausted.
+    fn __anext__(&self, py: Python<'_>) -> PyResult<Option<PyObject>> {
+        let iterator = self.0.clone();
+        let future = pyo3_asyncio::tokio::future_into_py(py, async move {
+            let mut inner_iterator = iterator.lock().await;
+            match inner_iterator.advance().await {
+                Ok(true) => match inner_iterator.deserialize_current() {
+                    Ok(v) => Ok(index_binding::IndexModel::from(v)),
+                    Err(e) => Err(to_py_err(e)),
+                },
+                Ok(false) => Err(PyErr::new::<exceptions::PyStopAsyncIteration, _>(())),
+                Err(e) => Err(to_py_err(e)),
+            }
+        })?;
+        Ok(Some(future.into()))
+    }
+}
@@ -1,5 +1,10 @@
+use std::collections::HashMap;
+
 use pyo3::{exceptions, ffi, prelude::*, types::PyString};
 
+use super::errors_binding;
+use super::options_binding::CodecOptions;
+
 #[allow(non_snake_case)]
 pub fn PyNone(py: Python) -> PyObject {
     unsafe { py.from_borrowed_ptr::<PyAny>(ffi::Py_None()).into_py(py) }
@@ -13,3 +18,24 @@ pub fn key_is_string(key: &PyAny) -> PyResult<()> {
     }
     Ok(())
 }
+
+/// Raises the error as the `ruson.errors` exception matching its kind. The exception keeps the
+/// server's code, code name and error labels, such as "TransientTransactionError".
+pub fn to_py_err(e: mongodb::error::Error) -> PyErr {
+    to_py_err_with_inserted_ids(e, HashMap::new(), &CodecOptions::default())
+}
+
+/// Like `to_py_err`, for an `insert_many` that still inserted the documents in `inserted_ids`.
+/// The ids are read with the collection's `codec`.
+pub fn to_py_err_with_inserted_ids(
+    e: mongodb::error::Error,
+    inserted_ids: HashMap<usize, mongodb::bson::Bson>,
+    codec: &CodecOptions,
+) -> PyErr {
+    Python::with_gil(
+        |py| match errors_binding::new_error(py, e, inserted_ids, codec) {
+            Ok(err) => err,
+            Err(err) => err,
+        },
+    )
+}
@@ -0,0 +1,320 @@
+use mongodb::bson::uuid::UuidRepresentation;
+use pyo3::{exceptions, prelude::*};
+
+use crate::interface;
+
+use super::bson_binding::EncodeUuids;
+use super::document_binding::Document;
+
+/// An update given either as a document of update operators or as an aggregation pipeline.
+#[derive(Clone, FromPyObject)]
+pub enum UpdateModifications {
+    Document(Document),
+    Pipeline(Vec<Document>),
+}
+
+impl IntoPy<PyObject> for UpdateModifications {
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        match self {
+            UpdateModifications::Document(document) => document.into_py(py),
+            UpdateModifications::Pipeline(pipeline) => pipeline.into_py(py),
+        }
+    }
+}
+
+impl From<UpdateModifications> for mongodb::bson::Bson {
+    fn from(value: UpdateModifications) -> Self {
+        match value {
+            UpdateModifications::Document(document) => mongodb::bson::Bson::Document(document.0),
+            UpdateModifications::Pipeline(pipeline) => mongodb::bson::Bson::Array(
+                pipeline
+                    .into_iter()
+                    .map(|d| mongodb::bson::Bson::Document(d.0))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl From<UpdateModifications> for mongodb::options::UpdateModifications {
+    fn from(value: UpdateModifications) -> Self {
+        match value {
+            UpdateModifications::Document(document) => {
+                mongodb::options::UpdateModifications::Document(document.0)
+            }
+            UpdateModifications::Pipeline(pipeline) => {
+                mongodb::options::UpdateModifications::Pipeline(
+                    pipeline.into_iter().map(|d| d.0).collect(),
+                )
+            }
+        }
+    }
+}
+
+impl EncodeUuids for UpdateModifications {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        match self {
+            UpdateModifications::Document(document) => document.encode_uuids(representation),
+            UpdateModifications::Pipeline(pipeline) => pipeline.encode_uuids(representation),
+        }
+    }
+}
+
+fn check_update(update: &UpdateModifications) -> PyResult<()> {
+    let documents = match update {
+        UpdateModifications::Document(document) => vec![document],
+        UpdateModifications::Pipeline(pipeline) => pipeline.iter().collect(),
+    };
+    for document in documents {
+        match document.0.keys().next() {
+            Some(key) if key.starts_with('$') => (),
+            _ => {
+                return Err(PyErr::new::<exceptions::PyValueError, _>(
+                    "Update document must only contain update operators",
+                ))
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_replacement(replacement: &Document) -> PyResult<()> {
+    match replacement.0.keys().next() {
+        Some(key) if key.starts_with('$') => Err(PyErr::new::<exceptions::PyValueError, _>(
+            "Replacement document must not contain update operators",
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct InsertOne {
+    /// The document to insert. An `_id` is generated when it is missing.
+    pub document: Document,
+}
+
+#[pymethods]
+impl InsertOne {
+    #[new]
+    fn new(document: Document) -> Self {
+        Self { document }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.InsertOne(...)".to_owned()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct UpdateOne {
+    /// Selects the document to update.
+    pub filter: Document,
+
+    /// The update operators or pipeline to apply to the first matched document.
+    pub update: UpdateModifications,
+
+    /// Inserts a new document when no document matches the filter.
+    pub upsert: Option<bool>,
+
+    /// Determines which array elements the update applies to.
+    pub array_filters: Option<Vec<Document>>,
+}
+
+#[pymethods]
+impl UpdateOne {
+    #[new]
+    fn new(
+        filter: Document,
+        update: UpdateModifications,
+        upsert: Option<bool>,
+        array_filters: Option<Vec<Document>>,
+    ) -> PyResult<Self> {
+        check_update(&update)?;
+        Ok(Self {
+            filter,
+            update,
+            upsert,
+            array_filters,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.UpdateOne(...)".to_owned()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct UpdateMany {
+    /// Selects the documents to update.
+    pub filter: Document,
+
+    /// The update operators or pipeline to apply to every matched document.
+    pub update: UpdateModifications,
+
+    /// Inserts a new document when no document matches the filter.
+    pub upsert: Option<bool>,
+
+    /// Determines which array elements the update applies to.
+    pub array_filters: Option<Vec<Document>>,
+}
+
+#[pymethods]
+impl UpdateMany {
+    #[new]
+    fn new(
+        filter: Document,
+        update: UpdateModifications,
+        upsert: Option<bool>,
+        array_filters: Option<Vec<Document>>,
+    ) -> PyResult<Self> {
+        check_update(&update)?;
+        Ok(Self {
+            filter,
+            update,
+            upsert,
+            array_filters,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.UpdateMany(...)".to_owned()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct ReplaceOne {
+    /// Selects the document to replace.
+    pub filter: Document,
+
+    /// The document that replaces the first matched document.
+    pub replacement: Document,
+
+    /// Inserts the replacement when no document matches the filter.
+    pub upsert: Option<bool>,
+}
+
+#[pymethods]
+impl ReplaceOne {
+    #[new]
+    fn new(filter: Document, replacement: Document, upsert: Option<bool>) -> PyResult<Self> {
+        check_replacement(&replacement)?;
+        Ok(Self {
+            filter,
+            replacement,
+            upsert,
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.ReplaceOne(...)".to_owned()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct DeleteOne {
+    /// Selects the document to delete.
+    pub filter: Document,
+}
+
+#[pymethods]
+impl DeleteOne {
+    #[new]
+    fn new(filter: Document) -> Self {
+        Self { filter }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.DeleteOne(...)".to_owned()
+    }
+}
+
+#[pyclass(frozen, get_all, module = "ruson.types")]
+#[derive(Clone)]
+pub struct DeleteMany {
+    /// Selects the documents to delete.
+    pub filter: Document,
+}
+
+#[pymethods]
+impl DeleteMany {
+    #[new]
+    fn new(filter: Document) -> Self {
+        Self { filter }
+    }
+
+    fn __repr__(&self) -> String {
+        "ruson.types.DeleteMany(...)".to_owned()
+    }
+}
+
+#[derive(FromPyObject)]
+pub enum WriteModel {
+    InsertOne(InsertOne),
+    UpdateOne(UpdateOne),
+    UpdateMany(UpdateMany),
+    ReplaceOne(ReplaceOne),
+    DeleteOne(DeleteOne),
+    DeleteMany(DeleteMany),
+}
+
+impl EncodeUuids for WriteModel {
+    fn encode_uuids(&mut self, representation: Option<UuidRepresentation>) -> PyResult<()> {
+        match self {
+            WriteModel::InsertOne(m) => m.document.encode_uuids(representation),
+            WriteModel::UpdateOne(m) => {
+                m.filter.encode_uuids(representation)?;
+                m.update.encode_uuids(representation)?;
+                m.array_filters.encode_uuids(representation)
+            }
+            WriteModel::UpdateMany(m) => {
+                m.filter.encode_uuids(representation)?;
+                m.update.encode_uuids(representation)?;
+                m.array_filters.encode_uuids(representation)
+            }
+            WriteModel::ReplaceOne(m) => {
+                m.filter.encode_uuids(representation)?;
+                m.replacement.encode_uuids(representation)
+            }
+            WriteModel::DeleteOne(m) => m.filter.encode_uuids(representation),
+            WriteModel::DeleteMany(m) => m.filter.encode_uuids(representation),
+        }
+    }
+}
+
+fn into_documents(documents: Option<Vec<Document>>) -> Option<Vec<mongodb::bson::Document>> {
+    documents.map(|documents| documents.into_iter().map(|d| d.0).collect())
+}
+
+impl From<WriteModel> for interface::WriteModel {
+    fn from(value: WriteModel) -> Self {
+        match value {
+            WriteModel::InsertOne(m) => interface::WriteModel::InsertOne {
+                document: m.document.0,
+            },
+            WriteModel::UpdateOne(m) => interface::WriteModel::UpdateOne {
+                filter: m.filter.0,
+                update: m.update.into(),
+                upsert: m.upsert,
+                array_filters: into_documents(m.array_filters),
+            },
+            WriteModel::UpdateMany(m) => interface::WriteModel::UpdateMany {
+                filter: m.filter.0,
+                update: m.update.into(),
+                upsert: m.upsert,
+                array_filters: into_documents(m.array_filters),
+            },
+            WriteModel::ReplaceOne(m) => interface::WriteModel::ReplaceOne {
+                filter: m.filter.0,
+                replacement: m.replacement.0,
+                upsert: m.upsert,
+            },
+            WriteModel::DeleteOne(m) => interface::WriteModel::DeleteOne { filter: m.filter.0 },
+            WriteModel::DeleteMany(m) => interface::WriteModel::DeleteMany { filter: m.filter.0 },
+        }
+    }
+}
@@ -1,40 +1,246 @@
 use mongodb::{
-    bson::{Bson, Document},
-    error::Result,
+    bson::{self, doc, oid::ObjectId, Bson, Document, RawDocument, RawDocumentBuf},
+    change_stream::{
+        event::{ChangeStreamEvent, ResumeToken},
+        session::SessionChangeStream,
+        ChangeStream,
+    },
+    error::{BulkWriteError, Error, ErrorKind, Result, WriteConcernError},
+    event::command::{CommandEventHandler, CommandSucceededEvent},
     options::{
-        AggregateOptions, CountOptions, CreateIndexOptions, DistinctOptions, DropIndexOptions,
-        FindOneOptions, FindOptions, ListIndexesOptions, TransactionOptions, UpdateOptions,
+        AggregateOptions, ChangeStreamOptions, ClientOptions, CountOptions, CreateIndexOptions,
+        DeleteOptions, DistinctOptions, DropIndexOptions, EstimatedDocumentCountOptions,
+        FindOneAndDeleteOptions, FindOneAndReplaceOptions, FindOneAndUpdateOptions, FindOneOptions,
+        FindOptions, InsertManyOptions, InsertOneOptions, ListIndexesOptions, ReplaceOptions,
+        ReturnDocument, TransactionOptions, UpdateModifications, UpdateOptions,
     },
     results::{CreateIndexesResult, DeleteResult, InsertManyResult, InsertOneResult, UpdateResult},
-    Client, ClientSession, Collection, Cursor, IndexModel, SessionCursor,
+    Client, ClientSession, Collection, Cursor, Database, IndexModel, SessionCursor,
+};
+use serde::{de::Error as _, Deserialize};
+use std::{
+    cell::Cell,
+    collections::HashMap,
+    future::Future,
+    iter::Iterator,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tokio::{
+    sync::{mpsc, Mutex, Notify},
+    task::JoinHandle,
+    time::Instant,
 };
-use serde::Deserialize;
-use std::{iter::Iterator, sync::Arc, time::Duration};
-use tokio::sync::Mutex;
 
-const DEFAULT_TIMEOUT: u64 = 5;
+const MAX_WRITE_BATCH_SIZE: usize = 100_000;
+const MAX_BSON_OBJECT_SIZE: usize = 16 * 1024 * 1024;
+const BATCH_ENTRY_OVERHEAD: usize = 8;
+const RETRY_BACKOFF: Duration = Duration::from_millis(100);
+/// Server codes after which a change stream resumes, for servers that do not send the
+/// `ResumableChangeStreamError` label.
+const RESUMABLE_CODES: [i32; 17] = [
+    6, 7, 63, 89, 91, 133, 150, 189, 234, 262, 9001, 10107, 11600, 11602, 13388, 13435, 13436,
+];
+
+/// The time limit the server applies to an operation.
+#[derive(Clone, Copy)]
+pub(crate) enum Timeout {
+    Milliseconds(u64),
+    Unbounded,
+}
+
+impl Timeout {
+    /// Used when no timeout is configured on the client.
+    pub(crate) const DEFAULT: Timeout = Timeout::Milliseconds(5_000);
+
+    pub(crate) fn duration(self) -> Option<Duration> {
+        match self {
+            Timeout::Milliseconds(ms) => Some(Duration::from_millis(ms)),
+            Timeout::Unbounded => None,
+        }
+    }
+}
+
+tokio::task_local! {
+    /// The id of the cursor opened by the command that `open_cursor` is running.
+    static OPENED_CURSOR_ID: Cell<i64>;
+}
+
+/// Reads the id of the cursor a command opens from the server's reply, since the driver's cursors
+/// do not expose it.
+struct CursorIdRecorder;
+
+impl CommandEventHandler for CursorIdRecorder {
+    fn handle_command_succeeded_event(&self, event: CommandSucceededEvent) {
+        if let Ok(id) = event
+            .reply
+            .get_document("cursor")
+            .and_then(|cursor| cursor.get_i64("id"))
+        {
+            // Replies to commands run outside of `open_cursor` are not recorded.
+            let _ = OPENED_CURSOR_ID.try_with(|opened| opened.set(id));
+        }
+    }
+}
+
+/// Runs `open`, which opens a cursor, and wraps the cursor with its id.
+async fn open_cursor<T>(
+    open: impl Future<Output = Result<CursorType<T>>>,
+) -> Result<ResultIterator<T>> {
+    OPENED_CURSOR_ID
+        .scope(Cell::new(0), async {
+            let cursor = open.await?;
+            Ok(ResultIterator::new(
+                cursor,
+                OPENED_CURSOR_ID.with(Cell::get),
+            ))
+        })
+        .await
+}
+
+/// Closes an iterator from outside of the lock held by the operation in progress on it, which
+/// then stops instead of waiting for the server.
+#[derive(Clone, Default)]
+pub(crate) struct Closer(Arc<CloseSignal>);
+
+#[derive(Default)]
+struct CloseSignal {
+    closed: AtomicBool,
+    notify: Notify,
+}
+
+impl Closer {
+    pub(crate) fn close(&self) {
+        self.0.closed.store(true, Ordering::SeqCst);
+        self.0.notify.notify_waiters();
+    }
+
+    pub(crate) fn is_closed(&self) -> bool {
+        self.0.closed.load(Ordering::SeqCst)
+    }
+
+    /// Completes once `close` is called.
+    pub(crate) async fn closed(&self) {
+        // Created before the check, so a `close` right after it still wakes this up.
+        let notified = self.0.notify.notified();
+        if !self.is_closed() {
+            notified.await;
+        }
+    }
+}
 
 pub(crate) enum CursorType<T> {
     Session(SessionCursor<T>, Arc<Mutex<ClientSession>>),
     Plain(Cursor<T>),
+    Prefetch(Prefetch),
 }
 
+/// Documents fetched ahead of the caller by a background task that owns the cursor.
+pub(crate) struct Prefetch {
+    receiver: mpsc::Receiver<Result<RawDocumentBuf>>,
+    current: Option<RawDocumentBuf>,
+    task: JoinHandle<()>,
+}
+
+impl Drop for Prefetch {
+    fn drop(&mut self) {
+        // Dropping the task's cursor kills it on the server.
+        self.task.abort();
+    }
+}
+
+/// Wraps an open cursor. Dropping the iterator, or closing it, kills the cursor on the server
+/// unless it is already exhausted.
 pub(crate) struct ResultIterator<T> {
-    cursor: CursorType<T>,
+    cursor: Option<CursorType<T>>,
+    id: i64,
+    closer: Closer,
+    peeked: bool,
+    exhausted: bool,
 }
 
 impl<'a, T> ResultIterator<T> {
-    pub(crate) fn new(cursor: CursorType<T>) -> Self {
-        ResultIterator { cursor }
+    pub(crate) fn new(cursor: CursorType<T>, id: i64) -> Self {
+        ResultIterator {
+            cursor: Some(cursor),
+            id,
+            closer: Closer::default(),
+            peeked: false,
+            exhausted: false,
+        }
+    }
+
+    /// The id of the cursor on the server, which is 0 when the first batch held every result.
+    pub(crate) fn id(&self) -> i64 {
+        self.id
+    }
+
+    /// Closes the iterator, even while an operation holds it.
+    pub(crate) fn closer(&self) -> Closer {
+        self.closer.clone()
     }
 
     pub(crate) async fn advance(&mut self) -> Result<bool> {
-        match &mut self.cursor {
-            CursorType::Session(c, s) => {
+        if self.peeked {
+            self.peeked = false;
+            return Ok(true);
+        }
+        let advanced = tokio::select! {
+            advanced = Self::advance_cursor(&mut self.cursor) => Some(advanced?),
+            _ = self.closer.closed() => None,
+        };
+        let advanced = advanced.unwrap_or_else(|| {
+            self.close();
+            false
+        });
+        self.exhausted = !advanced;
+        Ok(advanced)
+    }
+
+    async fn advance_cursor(cursor: &mut Option<CursorType<T>>) -> Result<bool> {
+        match cursor {
+            Some(CursorType::Session(c, s)) => {
                 let mut session = s.lock().await;
                 c.advance(&mut session).await
             }
-            CursorType::Plain(c) => c.advance().await,
+            Some(CursorType::Plain(c)) => c.advance().await,
+            Some(CursorType::Prefetch(p)) => {
+                p.current = p.receiver.recv().await.transpose()?;
+                Ok(p.current.is_some())
+            }
+            None => Ok(false),
+        }
+    }
+
+    /// Whether another document is available. Fetches the next batch when the current one is
+    /// used up, without moving past the next document.
+    pub(crate) async fn has_next(&mut self) -> Result<bool> {
+        if !self.peeked {
+            self.peeked = self.advance().await?;
+        }
+        Ok(self.peeked)
+    }
+
+    /// Whether the cursor may still return documents.
+    pub(crate) fn alive(&self) -> bool {
+        self.cursor.is_some() && !self.exhausted
+    }
+
+    /// Kills the cursor on the server. Later calls to `advance` return false.
+    pub(crate) fn close(&mut self) {
+        self.cursor = None;
+        self.peeked = false;
+    }
+
+    fn current(&self) -> Option<&RawDocument> {
+        match &self.cursor {
+            Some(CursorType::Session(c, _)) => Some(c.current()),
+            Some(CursorType::Plain(c)) => Some(c.current()),
+            Some(CursorType::Prefetch(p)) => p.current.as_deref(),
+            None => None,
         }
     }
 
@@ -42,133 +248,705 @@ impl<'a, T> ResultIterator<T> {
     where
         T: Deserialize<'a>,
     {
-        match &self.cursor {
-            CursorType::Session(c, _) => c.deserialize_current(),
-            CursorType::Plain(c) => c.deserialize_current(),
+        match self.current() {
+            Some(document) => Ok(bson::from_slice(document.as_bytes())?),
+            None => Err(bson::de::Error::custom("Cursor is closed").into()),
+        }
+    }
+
+    /// The size in bytes of the current document's BSON.
+    pub(crate) fn current_size(&self) -> usize {
+        self.current()
+            .map_or(0, |document| document.as_bytes().len())
+    }
+}
+
+impl<T: Send + Sync + 'static> ResultIterator<T> {
+    /// Moves the cursor to a background task that fetches up to `buffer` documents ahead of the
+    /// caller, so the next batch is requested while the current one is being consumed.
+    pub(crate) fn prefetch(&mut self, buffer: usize) {
+        let cursor = match self.cursor.take() {
+            Some(CursorType::Prefetch(p)) => {
+                self.cursor = Some(CursorType::Prefetch(p));
+                return;
+            }
+            Some(cursor) => cursor,
+            None => return,
+        };
+        let mut source = ResultIterator::<T>::new(cursor, self.id);
+        let (sender, receiver) = mpsc::channel(buffer.max(1));
+        let task = tokio::spawn(async move {
+            loop {
+                let document = match source.advance().await {
+                    Ok(true) => match source.current() {
+                        Some(document) => Ok(document.to_raw_document_buf()),
+                        None => break,
+                    },
+                    Ok(false) => break,
+                    Err(e) => Err(e),
+                };
+                let failed = document.is_err();
+                if sender.send(document).await.is_err() || failed {
+                    break;
+                }
+            }
+        });
+        self.cursor = Some(CursorType::Prefetch(Prefetch {
+            receiver,
+            current: None,
+            task,
+        }));
+    }
+}
+
+pub(crate) type ChangeEvent = ChangeStreamEvent<Document>;
+
+pub(crate) enum ChangeStreamType {
+    Session(SessionChangeStream<ChangeEvent>, Arc<Mutex<ClientSession>>),
+    Plain(ChangeStream<ChangeEvent>),
+}
+
+/// Wraps an open change stream. The driver resumes the stream by itself after resumable
+/// errors. Dropping the stream, or closing it, kills its cursor on the server.
+pub(crate) struct ChangeStreamIterator {
+    stream: Option<ChangeStreamType>,
+    closer: Closer,
+}
+
+impl ChangeStreamIterator {
+    pub(crate) fn new(stream: ChangeStreamType) -> Self {
+        ChangeStreamIterator {
+            stream: Some(stream),
+            closer: Closer::default(),
+        }
+    }
+
+    /// Closes the stream, even while an operation holds it.
+    pub(crate) fn closer(&self) -> Closer {
+        self.closer.clone()
+    }
+
+    /// Waits for the next event. Returns `None` once the stream is closed or invalidated.
+    pub(crate) async fn next(&mut self) -> Result<Option<ChangeEvent>> {
+        loop {
+            let event = self.next_if_any().await?;
+            if event.is_some() || !self.alive() {
+                return Ok(event);
+            }
+        }
+    }
+
+    /// Returns the next buffered event, or makes a single request for more. Returns `None` when
+    /// the server has no new events within `max_await_time`, or once the stream is closed.
+    pub(crate) async fn next_if_any(&mut self) -> Result<Option<ChangeEvent>> {
+        let event = tokio::select! {
+            event = Self::next_from_stream(&mut self.stream) => Some(event?),
+            _ = self.closer.closed() => None,
+        };
+        match event {
+            Some(event) => Ok(event),
+            None => {
+                self.close();
+                Ok(None)
+            }
+        }
+    }
+
+    async fn next_from_stream(
+        stream: &mut Option<ChangeStreamType>,
+    ) -> Result<Option<ChangeEvent>> {
+        match stream {
+            Some(ChangeStreamType::Session(s, session)) => {
+                let mut session = session.lock().await;
+                s.next_if_any(&mut session).await
+            }
+            Some(ChangeStreamType::Plain(s)) => s.next_if_any().await,
+            None => Ok(None),
+        }
+    }
+
+    /// The token to resume the stream from after the last event returned.
+    pub(crate) fn resume_token(&self) -> Option<ResumeToken> {
+        match &self.stream {
+            Some(ChangeStreamType::Session(s, _)) => s.resume_token(),
+            Some(ChangeStreamType::Plain(s)) => s.resume_token(),
+            None => None,
+        }
+    }
+
+    /// Whether the stream may still return events.
+    pub(crate) fn alive(&self) -> bool {
+        match &self.stream {
+            Some(ChangeStreamType::Session(s, _)) => s.is_alive(),
+            Some(ChangeStreamType::Plain(s)) => s.is_alive(),
+            None => false,
+        }
+    }
+
+    /// Kills the stream's cursor on the server. Later calls to `next` return `None`.
+    pub(crate) fn close(&mut self) {
+        self.stream = None;
+    }
+}
+
+/// Where a change stream listens for events.
+#[derive(Clone)]
+pub(crate) enum WatchTarget {
+    Collection(Collection<Document>),
+    Database(Database),
+    Client(Client),
+}
+
+pub(crate) async fn watch(
+    target: WatchTarget,
+    pipeline: impl Iterator<Item = Document>,
+    options: Option<ChangeStreamOptions>,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<ChangeStreamIterator> {
+    match session {
+        Some(s) => {
+            let copy = s.clone();
+            let mut session = s.lock().await;
+            let stream = match target {
+                WatchTarget::Collection(c) => {
+                    c.watch_with_session(pipeline, options, &mut session)
+                        .await?
+                }
+                WatchTarget::Database(d) => {
+                    d.watch_with_session(pipeline, options, &mut session)
+                        .await?
+                }
+                WatchTarget::Client(c) => {
+                    c.watch_with_session(pipeline, options, &mut session)
+                        .await?
+                }
+            };
+            Ok(ChangeStreamIterator::new(ChangeStreamType::Session(
+                stream, copy,
+            )))
+        }
+        None => {
+            let stream = match target {
+                WatchTarget::Collection(c) => c.watch(pipeline, options).await?,
+                WatchTarget::Database(d) => d.watch(pipeline, options).await?,
+                WatchTarget::Client(c) => c.watch(pipeline, options).await?,
+            };
+            Ok(ChangeStreamIterator::new(ChangeStreamType::Plain(stream)))
+        }
+    }
+}
+
+/// Whether a change stream can be reopened after the error, as per the change streams spec.
+fn is_resumable(e: &Error) -> bool {
+    match e.kind.as_ref() {
+        ErrorKind::Io(_)
+        | ErrorKind::ConnectionPoolCleared { .. }
+        | ErrorKind::ServerSelection { .. } => true,
+        ErrorKind::Command(err) => {
+            err.code == 43
+                || e.contains_label("ResumableChangeStreamError")
+                || RESUMABLE_CODES.contains(&err.code)
+        }
+        _ => false,
+    }
+}
+
+/// Where and how often a `CheckpointedChangeStream` saves its resume token.
+pub(crate) struct Checkpoints {
+    /// The collection holding one checkpoint document per consumer.
+    pub(crate) collection: Collection<Document>,
+
+    /// The `_id` of the consumer's checkpoint document.
+    pub(crate) name: String,
+
+    /// Saves a checkpoint once this many events are processed.
+    pub(crate) every: Option<usize>,
+
+    /// Saves a checkpoint once this much time has passed since the last one.
+    pub(crate) interval: Option<Duration>,
+}
+
+/// A change stream that saves its resume token to a checkpoint collection and starts from the
+/// saved token when it is created again, e.g. after the process restarts.
+///
+/// Events are handed out in batches. A batch counts as processed when the next one is
+/// requested, or on `checkpoint`, so events are delivered at least once. Resumable errors
+/// reopen the stream after the last event handed out.
+pub(crate) struct CheckpointedChangeStream {
+    target: WatchTarget,
+    pipeline: Vec<Document>,
+    options: ChangeStreamOptions,
+    checkpoints: Checkpoints,
+    max_retries: u32,
+    stream: Option<ChangeStreamIterator>,
+    closer: Closer,
+    /// The token after the last event handed out.
+    delivered: Option<ResumeToken>,
+    /// How many events were handed out in the last batch.
+    unacknowledged: usize,
+    /// The token after the last processed event.
+    processed: Option<ResumeToken>,
+    /// How many events were processed since the last checkpoint.
+    pending: usize,
+    saved: Option<ResumeToken>,
+    saved_at: Instant,
+}
+
+impl CheckpointedChangeStream {
+    /// Opens the stream after the consumer's saved checkpoint, or as set by `options` when there
+    /// is none yet.
+    pub(crate) async fn start(
+        target: WatchTarget,
+        pipeline: Vec<Document>,
+        options: Option<ChangeStreamOptions>,
+        checkpoints: Checkpoints,
+        max_retries: u32,
+    ) -> Result<Self> {
+        let saved = match checkpoints
+            .collection
+            .find_one(doc! { "_id": &checkpoints.name }, None)
+            .await?
+        {
+            Some(mut checkpoint) => match checkpoint.remove("resume_token") {
+                Some(token) => Some(bson::from_bson::<ResumeToken>(token)?),
+                None => None,
+            },
+            None => None,
+        };
+        let mut stream = CheckpointedChangeStream {
+            target,
+            pipeline,
+            options: options.unwrap_or_default(),
+            checkpoints,
+            max_retries,
+            stream: None,
+            closer: Closer::default(),
+            delivered: saved.clone(),
+            unacknowledged: 0,
+            processed: saved.clone(),
+            pending: 0,
+            saved,
+            saved_at: Instant::now(),
+        };
+        stream.open().await?;
+        Ok(stream)
+    }
+
+    async fn open(&mut self) -> Result<()> {
+        let mut options = self.options.clone();
+        if let Some(token) = &self.delivered {
+            // Unlike `resume_after`, `start_after` also accepts the token of an invalidate event.
+            options.start_after = Some(token.clone());
+            options.resume_after = None;
+            options.start_at_operation_time = None;
+        }
+        let mut stream = watch(
+            self.target.clone(),
+            self.pipeline.clone().into_iter(),
+            Some(options),
+            None,
+        )
+        .await?;
+        // Closing this stream also stops a request in progress on the one it reads from.
+        stream.closer = self.closer.clone();
+        // Reopening from the initial token does not miss events sent before the first batch.
+        if self.delivered.is_none() {
+            self.delivered = stream.resume_token();
+        }
+        self.stream = Some(stream);
+        Ok(())
+    }
+
+    /// Returns between one and `max_events` events, waiting for the first one. The batch ends
+    /// early once the server has no new events within `max_await_time`. Returns an empty batch
+    /// once the stream is closed or invalidated. Marks the previous batch as processed.
+    pub(crate) async fn next_batch(&mut self, max_events: usize) -> Result<Vec<ChangeEvent>> {
+        self.acknowledge();
+        self.save_if_due().await?;
+        let mut batch = Vec::new();
+        let mut retries = 0;
+        while !self.closer.is_closed() && batch.len() < max_events.max(1) {
+            let stream = match &mut self.stream {
+                Some(stream) => stream,
+                None => {
+                    if let Err(e) = self.open().await {
+                        self.retry(e, &mut retries).await?;
+                    }
+                    continue;
+                }
+            };
+            match stream.next_if_any().await {
+                Ok(Some(event)) => {
+                    retries = 0;
+                    batch.push(event);
+                    self.delivered = stream.resume_token();
+                }
+                Ok(None) => {
+                    retries = 0;
+                    if !batch.is_empty() || !stream.alive() {
+                        break;
+                    }
+                    // Everything handed out is processed, so an idle stream can save its
+                    // latest token to keep the checkpoint from falling off the oplog.
+                    if let Some(token) = stream.resume_token() {
+                        self.delivered = Some(token.clone());
+                        self.processed = Some(token);
+                    }
+                    self.save_if_due().await?;
+                }
+                Err(e) => self.retry(e, &mut retries).await?,
+            }
+        }
+        self.unacknowledged = batch.len();
+        Ok(batch)
+    }
+
+    /// Drops the stream and waits before it is reopened, or returns the error when it is not
+    /// resumable or `retries` reached `max_retries`.
+    async fn retry(&mut self, e: Error, retries: &mut u32) -> Result<()> {
+        if !is_resumable(&e) || *retries >= self.max_retries {
+            return Err(e);
+        }
+        *retries += 1;
+        self.stream = None;
+        tokio::select! {
+            _ = tokio::time::sleep(RETRY_BACKOFF * 2u32.pow((*retries).min(6) - 1)) => {}
+            _ = self.closer.closed() => {}
+        }
+        Ok(())
+    }
+
+    fn acknowledge(&mut self) {
+        if self.unacknowledged > 0 {
+            self.pending += self.unacknowledged;
+            self.unacknowledged = 0;
+            self.processed = self.delivered.clone();
         }
     }
+
+    async fn save_if_due(&mut self) -> Result<()> {
+        let due = self.checkpoints.every.is_some_and(|n| self.pending >= n)
+            || self
+                .checkpoints
+                .interval
+                .is_some_and(|t| self.saved_at.elapsed() >= t);
+        if due {
+            self.save().await?;
+        }
+        Ok(())
+    }
+
+    async fn save(&mut self) -> Result<()> {
+        if self.processed != self.saved {
+            if let Some(token) = &self.processed {
+                let update = doc! {
+                    "$set": {
+                        "resume_token": bson::to_bson(token)?,
+                        "updated_at": bson::DateTime::now(),
+                    }
+                };
+                let options = UpdateOptions::builder().upsert(true).build();
+                self.checkpoints
+                    .collection
+                    .update_one(doc! { "_id": &self.checkpoints.name }, update, options)
+                    .await?;
+                self.saved = self.processed.clone();
+            }
+        }
+        self.pending = 0;
+        self.saved_at = Instant::now();
+        Ok(())
+    }
+
+    /// Marks every event handed out as processed and saves a checkpoint right away.
+    pub(crate) async fn checkpoint(&mut self) -> Result<()> {
+        self.acknowledge();
+        self.save().await
+    }
+
+    /// Whether the stream may still return events.
+    pub(crate) fn alive(&self) -> bool {
+        !self.closer.is_closed() && self.stream.as_ref().is_none_or(|s| s.alive())
+    }
+
+    /// Closes the stream, even while an operation holds it.
+    pub(crate) fn closer(&self) -> Closer {
+        self.closer.clone()
+    }
+
+    /// Kills the stream's cursor on the server without saving a checkpoint.
+    pub(crate) fn close(&mut self) {
+        self.closer.close();
+        self.stream = None;
+    }
 }
 
 pub(crate) async fn create_client(db_uri: &str) -> Result<Client> {
-    Client::with_uri_str(db_uri).await
+    let mut options = ClientOptions::parse(db_uri).await?;
+    options.command_event_handler = Some(Arc::new(CursorIdRecorder));
+    Client::with_options(options)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(crate) async fn find_one(
     collection: Collection<Document>,
     filter: Document,
     skip: Option<u64>,
     sort: Option<Document>,
     projection: Option<Document>,
-    timeout: Option<u64>,
+    options: Option<FindOneOptions>,
+    timeout: Timeout,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<Option<Document>> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = FindOneOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .skip(skip)
+    let max_time = timeout.duration();
+    let mut options = options.unwrap_or_default();
+    options.max_time = max_time;
+    options.skip = skip;
+    options.sort = sort;
+    options.projection = projection;
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection
+                .find_one_with_session(filter, options, &mut session)
+                .await
+        }
+        None => collection.find_one(filter, options).await,
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn find_one_and_update(
+    collection: Collection<Document>,
+    update: UpdateModifications,
+    filter: Document,
+    return_document: Option<ReturnDocument>,
+    sort: Option<Document>,
+    projection: Option<Document>,
+    upsert: Option<bool>,
+    array_filters: Option<Vec<Document>>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<Option<Document>> {
+    let max_time = timeout.duration();
+    let options = FindOneAndUpdateOptions::builder()
+        .max_time(max_time)
+        .return_document(return_document)
         .sort(sort)
         .projection(projection)
+        .upsert(upsert)
+        .array_filters(array_filters)
         .build();
     match session {
         Some(s) => {
             let mut session = s.lock().await;
             collection
-                .find_one_with_session(filter, options, &mut session)
+                .find_one_and_update_with_session(filter, update, options, &mut session)
+                .await
+        }
+        None => {
+            collection
+                .find_one_and_update(filter, update, options)
                 .await
         }
-        None => collection.find_one(filter, options).await,
     }
 }
 
-pub(crate) async fn find_many(
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn find_one_and_replace(
     collection: Collection<Document>,
-    filter: Option<Document>,
-    skip: Option<u64>,
-    limit: Option<i64>,
+    replacement: Document,
+    filter: Document,
+    return_document: Option<ReturnDocument>,
     sort: Option<Document>,
-    batch_size: Option<u32>,
     projection: Option<Document>,
-    timeout: Option<u64>,
+    upsert: Option<bool>,
+    timeout: Timeout,
     session: Option<Arc<Mutex<ClientSession>>>,
-) -> Result<ResultIterator<Document>> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = FindOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .max_await_time(Duration::from_secs(timeout_value))
-        .no_cursor_timeout(false)
-        .cursor_type(mongodb::options::CursorType::NonTailable)
-        .skip(skip)
-        .limit(limit)
+) -> Result<Option<Document>> {
+    let max_time = timeout.duration();
+    let options = FindOneAndReplaceOptions::builder()
+        .max_time(max_time)
+        .return_document(return_document)
         .sort(sort)
-        .batch_size(batch_size)
         .projection(projection)
+        .upsert(upsert)
         .build();
     match session {
         Some(s) => {
-            let copy = s.clone();
             let mut session = s.lock().await;
-            let cursor = collection
-                .find_with_session(filter, options, &mut session)
-                .await?;
-            Ok(ResultIterator::new(CursorType::Session(cursor, copy)))
+            collection
+                .find_one_and_replace_with_session(filter, replacement, options, &mut session)
+                .await
         }
         None => {
-            let cursor = collection.find(filter, options).await?;
-            Ok(ResultIterator::new(CursorType::Plain(cursor)))
+            collection
+                .find_one_and_replace(filter, replacement, options)
+                .await
+        }
+    }
+}
+
+pub(crate) async fn find_one_and_delete(
+    collection: Collection<Document>,
+    filter: Document,
+    sort: Option<Document>,
+    projection: Option<Document>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<Option<Document>> {
+    let max_time = timeout.duration();
+    let options = FindOneAndDeleteOptions::builder()
+        .max_time(max_time)
+        .sort(sort)
+        .projection(projection)
+        .build();
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection
+                .find_one_and_delete_with_session(filter, options, &mut session)
+                .await
         }
+        None => collection.find_one_and_delete(filter, options).await,
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+pub(crate) async fn find_many(
+    collection: Collection<Document>,
+    filter: Option<Document>,
+    skip: Option<u64>,
+    limit: Option<i64>,
+    sort: Option<Document>,
+    batch_size: Option<u32>,
+    projection: Option<Document>,
+    options: Option<FindOptions>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<ResultIterator<Document>> {
+    let max_time = timeout.duration();
+    let mut options = options.unwrap_or_default();
+    options.max_time = max_time;
+    options.max_await_time = options.max_await_time.or(max_time);
+    options.cursor_type = options
+        .cursor_type
+        .or(Some(mongodb::options::CursorType::NonTailable));
+    options.skip = skip;
+    options.limit = limit;
+    options.sort = sort;
+    options.batch_size = batch_size;
+    options.projection = projection;
+    open_cursor(async {
+        match session {
+            Some(s) => {
+                let copy = s.clone();
+                let mut session = s.lock().await;
+                let cursor = collection
+                    .find_with_session(filter, options, &mut session)
+                    .await?;
+                Ok(CursorType::Session(cursor, copy))
+            }
+            None => Ok(CursorType::Plain(collection.find(filter, options).await?)),
+        }
+    })
+    .await
+}
+
 pub(crate) async fn insert_one(
     collection: Collection<Document>,
     document: Document,
+    options: Option<InsertOneOptions>,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<InsertOneResult> {
     match session {
         Some(s) => {
             let mut session = s.lock().await;
             collection
-                .insert_one_with_session(document, None, &mut session)
+                .insert_one_with_session(document, options, &mut session)
                 .await
         }
-        None => collection.insert_one(document, None).await,
+        None => collection.insert_one(document, options).await,
     }
 }
 
+/// A failed `insert_many`, with the ids of the documents it still inserted.
+pub(crate) struct InsertManyError {
+    pub(crate) error: Error,
+    pub(crate) inserted_ids: HashMap<usize, Bson>,
+}
+
 pub(crate) async fn insert_many(
     collection: Collection<Document>,
     documents: impl Iterator<Item = Document>,
+    options: Option<InsertManyOptions>,
     session: Option<Arc<Mutex<ClientSession>>>,
-) -> Result<InsertManyResult> {
-    match session {
+) -> std::result::Result<InsertManyResult, InsertManyError> {
+    // The driver does not report the ids it generated when the insert fails, so generate them
+    // here.
+    let documents = documents
+        .map(|mut document| {
+            if !document.contains_key("_id") {
+                let mut with_id = doc! { "_id": ObjectId::new() };
+                with_id.extend(document);
+                document = with_id;
+            }
+            document
+        })
+        .collect::<Vec<_>>();
+    let ordered = options.as_ref().and_then(|o| o.ordered).unwrap_or(true);
+    let result = match session {
         Some(s) => {
             let mut session = s.lock().await;
             collection
-                .insert_many_with_session(documents, None, &mut session)
+                .insert_many_with_session(&documents, options, &mut session)
                 .await
         }
-        None => collection.insert_many(documents, None).await,
-    }
+        None => collection.insert_many(&documents, options).await,
+    };
+    result.map_err(|error| {
+        let inserted_ids = match error.kind.as_ref() {
+            ErrorKind::BulkWrite(failure) => {
+                let failed = failure
+                    .write_errors
+                    .iter()
+                    .flatten()
+                    .map(|e| e.index)
+                    .collect::<Vec<_>>();
+                // An ordered insert stops at the first failed document.
+                let stop = if ordered {
+                    failed.iter().min().copied().unwrap_or(documents.len())
+                } else {
+                    documents.len()
+                };
+                documents
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| *i < stop && !failed.contains(i))
+                    .filter_map(|(i, d)| d.get("_id").map(|id| (i, id.clone())))
+                    .collect()
+            }
+            _ => HashMap::new(),
+        };
+        InsertManyError {
+            error,
+            inserted_ids,
+        }
+    })
 }
 
 pub(crate) async fn update_one(
     collection: Collection<Document>,
-    update: Document,
+    update: UpdateModifications,
     filter: Document,
     upsert: Option<bool>,
     array_filters: Option<Vec<Document>>,
+    options: Option<UpdateOptions>,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<UpdateResult> {
-    let options = UpdateOptions::builder()
-        .upsert(upsert)
-        .array_filters(array_filters)
-        .build();
+    let mut options = options.unwrap_or_default();
+    options.upsert = upsert;
+    options.array_filters = array_filters;
     match session {
         Some(s) => {
             let mut session = s.lock().await;
@@ -180,35 +958,367 @@ pub(crate) async fn update_one(
     }
 }
 
+pub(crate) async fn update_many(
+    collection: Collection<Document>,
+    update: UpdateModifications,
+    filter: Document,
+    upsert: Option<bool>,
+    array_filters: Option<Vec<Document>>,
+    options: Option<UpdateOptions>,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<UpdateResult> {
+    let mut options = options.unwrap_or_default();
+    options.upsert = upsert;
+    options.array_filters = array_filters;
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection
+                .update_many_with_session(filter, update, options, &mut session)
+                .await
+        }
+        None => collection.update_many(filter, update, options).await,
+    }
+}
+
+pub(crate) async fn replace_one(
+    collection: Collection<Document>,
+    replacement: Document,
+    filter: Document,
+    upsert: Option<bool>,
+    options: Option<ReplaceOptions>,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<UpdateResult> {
+    let mut options = options.unwrap_or_default();
+    options.upsert = upsert;
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection
+                .replace_one_with_session(filter, replacement, options, &mut session)
+                .await
+        }
+        None => collection.replace_one(filter, replacement, options).await,
+    }
+}
+
+pub(crate) enum WriteModel {
+    InsertOne {
+        document: Document,
+    },
+    UpdateOne {
+        filter: Document,
+        update: Bson,
+        upsert: Option<bool>,
+        array_filters: Option<Vec<Document>>,
+    },
+    UpdateMany {
+        filter: Document,
+        update: Bson,
+        upsert: Option<bool>,
+        array_filters: Option<Vec<Document>>,
+    },
+    ReplaceOne {
+        filter: Document,
+        replacement: Document,
+        upsert: Option<bool>,
+    },
+    DeleteOne {
+        filter: Document,
+    },
+    DeleteMany {
+        filter: Document,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum WriteCommand {
+    Insert,
+    Update,
+    Delete,
+}
+
+impl WriteCommand {
+    fn name(&self) -> &'static str {
+        match self {
+            WriteCommand::Insert => "insert",
+            WriteCommand::Update => "update",
+            WriteCommand::Delete => "delete",
+        }
+    }
+
+    fn payload_name(&self) -> &'static str {
+        match self {
+            WriteCommand::Insert => "documents",
+            WriteCommand::Update => "updates",
+            WriteCommand::Delete => "deletes",
+        }
+    }
+}
+
+impl WriteModel {
+    fn command(&self) -> WriteCommand {
+        match self {
+            WriteModel::InsertOne { .. } => WriteCommand::Insert,
+            WriteModel::UpdateOne { .. }
+            | WriteModel::UpdateMany { .. }
+            | WriteModel::ReplaceOne { .. } => WriteCommand::Update,
+            WriteModel::DeleteOne { .. } | WriteModel::DeleteMany { .. } => WriteCommand::Delete,
+        }
+    }
+
+    /// Builds the statement sent inside the `documents`, `updates` or `deletes` array of the
+    /// write command, along with the `_id` generated for inserts.
+    fn into_statement(self) -> (Document, Option<Bson>) {
+        match self {
+            WriteModel::InsertOne { document } => {
+                let id = match document.get("_id") {
+                    Some(id) => id.clone(),
+                    None => Bson::ObjectId(ObjectId::new()),
+                };
+                let mut statement = doc! { "_id": id.clone() };
+                statement.extend(document.into_iter().filter(|(k, _)| k != "_id"));
+                (statement, Some(id))
+            }
+            WriteModel::UpdateOne {
+                filter,
+                update,
+                upsert,
+                array_filters,
+            } => (
+                update_statement(filter, update, upsert, array_filters, false),
+                None,
+            ),
+            WriteModel::UpdateMany {
+                filter,
+                update,
+                upsert,
+                array_filters,
+            } => (
+                update_statement(filter, update, upsert, array_filters, true),
+                None,
+            ),
+            WriteModel::ReplaceOne {
+                filter,
+                replacement,
+                upsert,
+            } => (
+                update_statement(filter, Bson::Document(replacement), upsert, None, false),
+                None,
+            ),
+            WriteModel::DeleteOne { filter } => (doc! { "q": filter, "limit": 1 }, None),
+            WriteModel::DeleteMany { filter } => (doc! { "q": filter, "limit": 0 }, None),
+        }
+    }
+}
+
+fn update_statement(
+    filter: Document,
+    update: Bson,
+    upsert: Option<bool>,
+    array_filters: Option<Vec<Document>>,
+    multi: bool,
+) -> Document {
+    let mut statement = doc! { "q": filter, "u": update, "multi": multi };
+    if let Some(upsert) = upsert {
+        statement.insert("upsert", upsert);
+    }
+    if let Some(array_filters) = array_filters {
+        statement.insert("arrayFilters", array_filters);
+    }
+    statement
+}
+
+struct WriteBatch {
+    command: WriteCommand,
+    indexes: Vec<usize>,
+    statements: Vec<Document>,
+    size: usize,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WriteCommandResponse {
+    n: u64,
+    n_modified: Option<u64>,
+    #[serde(default)]
+    upserted: Vec<UpsertedEntry>,
+    #[serde(default)]
+    write_errors: Vec<BulkWriteError>,
+    write_concern_error: Option<WriteConcernError>,
+}
+
+#[derive(Deserialize)]
+struct UpsertedEntry {
+    index: usize,
+    #[serde(rename = "_id")]
+    id: Bson,
+}
+
+#[derive(Default)]
+pub(crate) struct BulkWriteResult {
+    pub(crate) inserted_count: u64,
+    pub(crate) matched_count: u64,
+    pub(crate) modified_count: u64,
+    pub(crate) deleted_count: u64,
+    pub(crate) upserted_count: u64,
+    pub(crate) inserted_ids: HashMap<usize, Bson>,
+    pub(crate) upserted_ids: HashMap<usize, Bson>,
+    pub(crate) write_errors: Vec<BulkWriteError>,
+    pub(crate) write_concern_errors: Vec<WriteConcernError>,
+}
+
+pub(crate) async fn bulk_write(
+    collection: Collection<Document>,
+    models: Vec<WriteModel>,
+    ordered: Option<bool>,
+    session: Option<Arc<Mutex<ClientSession>>>,
+    in_transaction: bool,
+) -> Result<BulkWriteResult> {
+    let ordered = ordered.unwrap_or(true);
+    let mut models = models.into_iter().enumerate().collect::<Vec<_>>();
+    if !ordered {
+        // Unordered writes may be regrouped so every command kind is sent in as few batches
+        // as possible. The sort is stable, so operations keep their relative order.
+        models.sort_by_key(|(_, m)| m.command());
+    }
+
+    let mut inserted_ids = HashMap::new();
+    let mut batches: Vec<WriteBatch> = Vec::new();
+    for (index, model) in models {
+        let command = model.command();
+        let (statement, inserted_id) = model.into_statement();
+        if let Some(id) = inserted_id {
+            inserted_ids.insert(index, id);
+        }
+        let size = bson::to_vec(&statement)?.len() + BATCH_ENTRY_OVERHEAD;
+        match batches.last_mut() {
+            Some(batch)
+                if batch.command == command
+                    && batch.statements.len() < MAX_WRITE_BATCH_SIZE
+                    && batch.size + size <= MAX_BSON_OBJECT_SIZE =>
+            {
+                batch.indexes.push(index);
+                batch.statements.push(statement);
+                batch.size += size;
+            }
+            _ => batches.push(WriteBatch {
+                command,
+                indexes: vec![index],
+                statements: vec![statement],
+                size,
+            }),
+        }
+    }
+
+    let database = collection.client().database(&collection.namespace().db);
+    // Operations inside a transaction inherit the transaction's write concern and must not set
+    // their own.
+    let write_concern = match collection.write_concern() {
+        Some(wc) if !in_transaction => Some(bson::to_document(wc)?).filter(|wc| !wc.is_empty()),
+        _ => None,
+    };
+
+    let mut result = BulkWriteResult::default();
+    for batch in batches {
+        let mut command = doc! {
+            batch.command.name(): collection.name(),
+            batch.command.payload_name(): batch.statements,
+            "ordered": ordered,
+        };
+        if let Some(wc) = &write_concern {
+            command.insert("writeConcern", wc.clone());
+        }
+        let response = match &session {
+            Some(s) => {
+                let mut session = s.lock().await;
+                database
+                    .run_command_with_session(command, None, &mut session)
+                    .await?
+            }
+            None => database.run_command(command, None).await?,
+        };
+        let response: WriteCommandResponse = bson::from_document(response)?;
+
+        match batch.command {
+            WriteCommand::Insert => result.inserted_count += response.n,
+            WriteCommand::Update => {
+                let upserted = response.upserted.len() as u64;
+                result.matched_count += response.n - upserted;
+                result.modified_count += response.n_modified.unwrap_or(0);
+                result.upserted_count += upserted;
+                for entry in response.upserted {
+                    result
+                        .upserted_ids
+                        .insert(batch.indexes[entry.index], entry.id);
+                }
+            }
+            WriteCommand::Delete => result.deleted_count += response.n,
+        }
+
+        let failed = !response.write_errors.is_empty();
+        let first_failure = response.write_errors.first().map(|e| e.index);
+        for mut error in response.write_errors {
+            error.index = batch.indexes[error.index];
+            result.write_errors.push(error);
+        }
+        if let Some(error) = response.write_concern_error {
+            result.write_concern_errors.push(error);
+        }
+
+        if batch.command == WriteCommand::Insert {
+            for (position, index) in batch.indexes.iter().enumerate() {
+                let executed = match (ordered, first_failure) {
+                    (true, Some(failure)) => position < failure,
+                    _ => true,
+                };
+                let errored = result.write_errors.iter().any(|e| e.index == *index);
+                if executed && !errored {
+                    if let Some(id) = inserted_ids.remove(index) {
+                        result.inserted_ids.insert(*index, id);
+                    }
+                }
+            }
+        }
+
+        if ordered && failed {
+            break;
+        }
+    }
+    Ok(result)
+}
+
 pub(crate) async fn delete_one(
     collection: Collection<Document>,
     filter: Document,
+    options: Option<DeleteOptions>,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<DeleteResult> {
     match session {
         Some(s) => {
             let mut session = s.lock().await;
             collection
-                .delete_one_with_session(filter, None, &mut session)
+                .delete_one_with_session(filter, options, &mut session)
                 .await
         }
-        None => collection.delete_one(filter, None).await,
+        None => collection.delete_one(filter, options).await,
     }
 }
 
 pub(crate) async fn delete_many(
     collection: Collection<Document>,
     filter: Document,
+    options: Option<DeleteOptions>,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<DeleteResult> {
     match session {
         Some(s) => {
             let mut session = s.lock().await;
             collection
-                .delete_many_with_session(filter, None, &mut session)
+                .delete_many_with_session(filter, options, &mut session)
                 .await
         }
-        None => collection.delete_many(filter, None).await,
+        None => collection.delete_many(filter, options).await,
     }
 }
 
@@ -216,48 +1326,42 @@ pub(crate) async fn aggregate(
     collection: Collection<Document>,
     pipeline: impl Iterator<Item = Document>,
     batch_size: Option<u32>,
-    timeout: Option<u64>,
+    options: Option<AggregateOptions>,
+    timeout: Timeout,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<ResultIterator<Document>> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = AggregateOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .max_await_time(Duration::from_secs(timeout_value))
-        .batch_size(batch_size)
-        .build();
-    match session {
-        Some(s) => {
-            let copy = s.clone();
-            let mut session = s.lock().await;
-            let cursor = collection
-                .aggregate_with_session(pipeline, options, &mut session)
-                .await?;
-            Ok(ResultIterator::new(CursorType::Session(cursor, copy)))
-        }
-        None => {
-            let cursor = collection.aggregate(pipeline, options).await?;
-            Ok(ResultIterator::new(CursorType::Plain(cursor)))
+    let max_time = timeout.duration();
+    let mut options = options.unwrap_or_default();
+    options.max_time = max_time;
+    options.max_await_time = max_time;
+    options.batch_size = batch_size;
+    open_cursor(async {
+        match session {
+            Some(s) => {
+                let copy = s.clone();
+                let mut session = s.lock().await;
+                let cursor = collection
+                    .aggregate_with_session(pipeline, options, &mut session)
+                    .await?;
+                Ok(CursorType::Session(cursor, copy))
+            }
+            None => Ok(CursorType::Plain(
+                collection.aggregate(pipeline, options).await?,
+            )),
         }
-    }
+    })
+    .await
 }
 
 pub(crate) async fn distinct(
     collection: Collection<Document>,
     field_name: &str,
     filter: Option<Document>,
-    timeout: Option<u64>,
+    timeout: Timeout,
     session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<Vec<Bson>> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = DistinctOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .build();
+    let max_time = timeout.duration();
+    let options = DistinctOptions::builder().max_time(max_time).build();
     match session {
         Some(s) => {
             let mut session = s.lock().await;
@@ -271,51 +1375,77 @@ pub(crate) async fn distinct(
 
 pub(crate) async fn list_indexes(
     collection: Collection<Document>,
-    timeout: Option<u64>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<ResultIterator<IndexModel>> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = ListIndexesOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .build();
-    let cursor = collection.list_indexes(options).await?;
-    Ok(ResultIterator::new(CursorType::Plain(cursor)))
+    let max_time = timeout.duration();
+    let options = ListIndexesOptions::builder().max_time(max_time).build();
+    open_cursor(async {
+        match session {
+            Some(s) => {
+                let copy = s.clone();
+                let mut session = s.lock().await;
+                let cursor = collection
+                    .list_indexes_with_session(options, &mut session)
+                    .await?;
+                Ok(CursorType::Session(cursor, copy))
+            }
+            None => Ok(CursorType::Plain(collection.list_indexes(options).await?)),
+        }
+    })
+    .await
 }
 
 pub(crate) async fn create_indexes(
     collection: Collection<Document>,
     indexes: impl Iterator<Item = IndexModel>,
-    timeout: Option<u64>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<CreateIndexesResult> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = CreateIndexOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .build();
-    collection.create_indexes(indexes, options).await
+    let max_time = timeout.duration();
+    let options = CreateIndexOptions::builder().max_time(max_time).build();
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection
+                .create_indexes_with_session(indexes, options, &mut session)
+                .await
+        }
+        None => collection.create_indexes(indexes, options).await,
+    }
 }
 
 pub(crate) async fn drop_indexes(
     collection: Collection<Document>,
     indexes: Option<impl Iterator<Item = String>>,
-    timeout: Option<u64>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<()> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = DropIndexOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
-        .build();
+    let max_time = timeout.duration();
+    let options = DropIndexOptions::builder().max_time(max_time).build();
+    if let Some(s) = session {
+        let mut session = s.lock().await;
+        return match indexes {
+            Some(idxs) => {
+                for index in idxs {
+                    collection
+                        .drop_index_with_session(index, options.clone(), &mut session)
+                        .await?;
+                }
+                Ok(())
+            }
+            None => {
+                collection
+                    .drop_indexes_with_session(options, &mut session)
+                    .await
+            }
+        };
+    }
     match indexes {
         Some(idxs) => {
             let mut session = collection.client().start_session(None).await?;
             let transaction_options = TransactionOptions::builder()
-                .max_commit_time(Duration::from_secs(timeout_value))
+                .max_commit_time(max_time)
                 .build();
             session.start_transaction(transaction_options).await?;
             for index in idxs {
@@ -332,18 +1462,48 @@ pub(crate) async fn drop_indexes(
 pub(crate) async fn count_documents(
     collection: Collection<Document>,
     filter: Option<Document>,
-    timeout: Option<u64>,
+    skip: Option<u64>,
+    limit: Option<u64>,
+    options: Option<CountOptions>,
+    timeout: Timeout,
+    session: Option<Arc<Mutex<ClientSession>>>,
 ) -> Result<u64> {
-    let timeout_value = match timeout {
-        Some(t) => t,
-        None => DEFAULT_TIMEOUT,
-    };
-    let options = CountOptions::builder()
-        .max_time(Duration::from_secs(timeout_value))
+    let max_time = timeout.duration();
+    let mut options = options.unwrap_or_default();
+    options.max_time = max_time;
+    options.skip = skip;
+    options.limit = limit;
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection
+                .count_documents_with_session(filter, options, &mut session)
+                .await
+        }
+        None => collection.count_documents(filter, options).await,
+    }
+}
+
+pub(crate) async fn estimated_document_count(
+    collection: Collection<Document>,
+    timeout: Timeout,
+) -> Result<u64> {
+    let max_time = timeout.duration();
+    let options = EstimatedDocumentCountOptions::builder()
+        .max_time(max_time)
         .build();
-    collection.count_documents(filter, options).await
+    collection.estimated_document_count(options).await
 }
 
-pub(crate) async fn drop(collection: Collection<Document>) -> Result<()> {
-    collection.drop(None).await
+pub(crate) async fn drop(
+    collection: Collection<Document>,
+    session: Option<Arc<Mutex<ClientSession>>>,
+) -> Result<()> {
+    match session {
+        Some(s) => {
+            let mut session = s.lock().await;
+            collection.drop_with_session(None, &mut session).await
+        }
+        None => collection.drop(None).await,
+    }
 }
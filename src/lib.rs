@@ -11,6 +11,7 @@ fn ruson(py: Python<'_>, m: &PyModule) -> PyResult<()> {
     bindings::collection(py, rust_module)?;
     bindings::iterator(py, rust_module)?;
     bindings::types(py, rust_module)?;
+    bindings::errors(py, rust_module)?;
     m.add_submodule(rust_module)?;
     Ok(())
 }
@@ -15,3 +15,9 @@ async def test_count(setup_connection: None):
     filter = {"name": "test-0"}
     count = await CountDoc.count_documents(filter=filter)
     assert count == 1
+
+    count = await CountDoc.count_documents(skip=1)
+    assert count == 1
+
+    count = await CountDoc.estimated_document_count()
+    assert count == 2
@@ -1,4 +1,5 @@
 from ruson.core.ruson_doc import RusonDoc
+from ruson.driver.types import ReturnDocument
 
 
 class UpdateDoc(RusonDoc):
@@ -44,3 +45,77 @@ async def test_update_one(setup_connection: None):
     doc = await UpdateDoc.find_one({"value": "value2"})
     assert doc["value"] == "value2"
     assert doc["update_value"] == "new_value2"
+
+
+async def test_update_many(setup_connection: None):
+    for i in range(3):
+        await UpdateDoc.insert_one({"value": "value", "update_value": f"update{i}"})
+
+    result = await UpdateDoc.update_many(
+        {"$set": {"update_value": "new_value"}}, {"value": "value"}
+    )
+    assert result.matched_count == 3
+    assert result.modified_count == 3
+    assert result.upserted_id is None
+
+    count = await UpdateDoc.count_documents({"update_value": "new_value"})
+    assert count == 3
+
+
+
+async def test_update_pipeline(setup_connection: None):
+    for i in range(3):
+        await UpdateDoc.insert_one({"value": f"value{i}", "update_value": "update"})
+
+    pipeline = [
+        {"$set": {"update_value": {"$concat": ["$update_value", "-", "$value"]}}},
+        {"$unset": "created_at"},
+    ]
+    result = await UpdateDoc.update_one(pipeline, {"value": "value0"})
+    assert result.modified_count == 1
+
+    doc = await UpdateDoc.find_one({"value": "value0"})
+    assert doc["update_value"] == "update-value0"
+    assert "created_at" not in doc
+
+    result = await UpdateDoc.update_many(pipeline, {"update_value": "update"})
+    assert result.modified_count == 2
+    assert await UpdateDoc.count_documents({"update_value": "update-value2"}) == 1
+
+async def test_replace_one(setup_connection: None):
+    await UpdateDoc.insert_one({"value": "value0", "update_value": "update_value0"})
+
+    result = await UpdateDoc(value="value0", update_value="replaced0").replace(
+        {"value": "value0"}
+    )
+    assert result.matched_count == 1
+    assert result.modified_count == 1
+    assert result.upserted_id is None
+
+    doc = await UpdateDoc.find_one({"value": "value0"})
+    assert doc["update_value"] == "replaced0"
+
+    result = await UpdateDoc.replace_one(
+        {"value": "value1", "update_value": "replaced1"},
+        {"value": "value1"},
+        upsert=True,
+    )
+    assert result.matched_count == 0
+    assert result.upserted_id is not None
+
+
+async def test_find_one_and_update(setup_connection: None):
+    await UpdateDoc.insert_one({"value": "value0", "update_value": "update_value0"})
+
+    doc = await UpdateDoc.find_one_and_update(
+        {"$set": {"update_value": "new_value0"}},
+        {"value": "value0"},
+        return_document=ReturnDocument.After,
+    )
+    assert doc["update_value"] == "new_value0"
+
+    doc = await UpdateDoc.find_one_and_delete({"value": "value0"})
+    assert doc["update_value"] == "new_value0"
+
+    doc = await UpdateDoc.find_one_and_delete({"value": "value0"})
+    assert doc is None
@@ -1,4 +1,12 @@
 from ruson.driver.client import create_client
+from ruson.driver.types import (
+    Document,
+    ReadConcern,
+    ReadPreference,
+    SessionOptions,
+    TransactionOptions,
+    WriteConcern,
+)
 
 
 async def test_create_client(db_uri: str):
@@ -22,6 +30,9 @@ async def test_list_databases(db_uri: str, expected_dbs: list[str]):
         if db not in dbs:
             assert False, f"Expected database {db} not found"
 
+    session = await client.create_session()
+    assert sorted(await client.list_databases(session=session)) == sorted(dbs)
+
 
 async def test_create_session(db_uri: str):
     client = await create_client(db_uri)
@@ -31,6 +42,184 @@ async def test_create_session(db_uri: str):
         assert False, f"Failed to create session with exception: {e}"
 
 
+async def test_session_options(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    collection = client[db_name][collection_name]
+
+    options = SessionOptions(
+        causal_consistency=True,
+        default_transaction_options=TransactionOptions(
+            write_concern=WriteConcern(w="majority")
+        ),
+    )
+    writer = await client.create_session(options)
+    assert "id" in await writer.session_id()
+    assert await writer.cluster_time() is None
+    assert await writer.operation_time() is None
+
+    await collection.insert_one(Document(name="causal"), session=writer)
+    cluster_time = await writer.cluster_time()
+    operation_time = await writer.operation_time()
+    assert cluster_time is not None
+    assert operation_time is not None
+
+    reader = await client.create_session(SessionOptions(causal_consistency=True))
+    assert await reader.session_id() != await writer.session_id()
+    await reader.advance_cluster_time(cluster_time)
+    await reader.advance_operation_time(operation_time)
+    reader_operation_time = await reader.operation_time()
+    assert reader_operation_time.timestamp == operation_time.timestamp
+    doc = await collection.find_one(Document(name="causal"), session=reader)
+    assert doc is not None
+
+    await writer.start_transaction()
+    await collection.insert_one(Document(name="default"), session=writer)
+    await writer.commit_transaction()
+
+    snapshot = await client.create_session(SessionOptions(snapshot=True))
+    assert await collection.count_documents(Document(), session=snapshot) == 12
+    try:
+        await snapshot.start_transaction()
+        assert False, "Started a transaction on a snapshot session"
+    except ValueError:
+        pass
+
+    try:
+        await reader.advance_operation_time(1)
+        assert False, "Advanced operation time with an integer"
+    except TypeError:
+        pass
+
+
+async def test_transactions(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    collection = client[db_name][collection_name]
+    total = await collection.count_documents(Document())
+
+    session = await client.create_session()
+    options = TransactionOptions(
+        read_concern=ReadConcern("snapshot"),
+        write_concern=WriteConcern(w="majority"),
+        read_preference=ReadPreference("primary"),
+        max_commit_time=1000,
+    )
+    await session.start_transaction(options)
+    assert session.in_transaction
+    await collection.insert_one(Document(name="aborted"), session=session)
+    await session.abort_transaction()
+    assert not session.in_transaction
+    assert await collection.count_documents(Document()) == total
+
+    await session.start_transaction()
+    await collection.insert_one(Document(name="committed"), session=session)
+    await session.commit_transaction()
+    assert await collection.count_documents(Document()) == total + 1
+
+    async with session:
+        await session.start_transaction()
+        await collection.insert_one(Document(name="context"), session=session)
+    assert await collection.count_documents(Document(name="context")) == 1
+    try:
+        await session.start_transaction()
+        assert False, "Started a transaction on a session ended by its context"
+    except ValueError:
+        pass
+
+    session = await client.create_session()
+    try:
+        async with session:
+            await session.start_transaction()
+            await collection.insert_one(Document(name="raised"), session=session)
+            raise RuntimeError()
+    except RuntimeError:
+        pass
+    assert not session.in_transaction
+    assert await collection.count_documents(Document(name="raised")) == 0
+
+    async def failing_abort():
+        raise ValueError("Simulated failed abort")
+
+    session = await client.create_session()
+    session.abort_transaction = failing_abort
+    try:
+        async with session:
+            await session.start_transaction()
+            await collection.insert_one(Document(name="aborted"), session=session)
+            raise RuntimeError()
+    except RuntimeError:
+        pass
+    assert not session.in_transaction
+
+    session = await client.create_session()
+    await session.start_transaction()
+    await collection.insert_one(Document(name="ended"), session=session)
+    await session.end_session()
+    assert await collection.count_documents(Document(name="ended")) == 0
+    try:
+        await session.start_transaction()
+        assert False, "Started a transaction on an ended session"
+    except ValueError:
+        pass
+
+    try:
+        ReadPreference("primary_only")
+        assert False, "Created read preference with an invalid mode"
+    except ValueError:
+        pass
+
+
+async def test_with_transaction(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    collection = client[db_name][collection_name]
+    session = await client.create_session()
+    attempts = []
+
+    async def callback(s):
+        assert s.in_transaction
+        attempts.append(s)
+        await collection.insert_one(Document(name="transaction"), session=s)
+        if len(attempts) == 1:
+            error = ValueError("Simulated transient error")
+            error.error_labels = ["TransientTransactionError"]
+            raise error
+        return len(attempts)
+
+    assert await session.with_transaction(callback) == 2
+    assert attempts == [session, session]
+    assert not session.in_transaction
+    assert await collection.count_documents(Document(name="transaction")) == 1
+
+    async def failing(s):
+        await collection.insert_one(Document(name="failed"), session=s)
+        raise RuntimeError()
+
+    try:
+        await session.with_transaction(failing, timeout=1)
+        assert False, "Transaction with a failing callback was committed"
+    except RuntimeError:
+        pass
+    assert await collection.count_documents(Document(name="failed")) == 0
+
+    try:
+        await collection.insert_one(Document(_id=1), session=session)
+        await collection.insert_one(Document(_id=1), session=session)
+        assert False, "Inserted a duplicate key"
+    except ValueError as e:
+        assert e.error_labels == []
+
+
+async def test_watch(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    async with await client.watch() as stream:
+        await client[db_name][collection_name].insert_one(Document(name="watched"))
+        event = await anext(stream)
+        assert event.operation_type == "insert"
+        assert event.ns.db == db_name
+        assert event.full_document["name"] == "watched"
+        assert stream.resume_token is not None
+    assert not stream.alive
+
+
 async def test_shutdown(db_uri: str):
     client = await create_client(db_uri)
     try:
@@ -1,6 +1,43 @@
+import asyncio
+from datetime import datetime, timedelta, timezone
+from uuid import UUID
+
 from ruson.driver.client import create_client
 from ruson.driver.collection import Collection
-from ruson.driver.types import Document, IndexModel, IndexOptions
+from ruson.driver.errors import (
+    BulkWriteError,
+    ConnectionFailure,
+    DuplicateKeyError,
+    ServerSelectionTimeout,
+    WriteError,
+)
+from ruson.driver.types import (
+    AggregateOptions,
+    Binary,
+    ChangeStreamOptions,
+    CodecOptions,
+    Collation,
+    CountOptions,
+    CursorType,
+    DatetimeMS,
+    DeleteMany,
+    DeleteOne,
+    DeleteOptions,
+    Document,
+    FindOptions,
+    IndexModel,
+    IndexOptions,
+    InsertOne,
+    InsertOptions,
+    ReadConcern,
+    ReplaceOne,
+    ReturnDocument,
+    Timeout,
+    UpdateMany,
+    UpdateOne,
+    UpdateOptions,
+    WriteConcern,
+)
 
 
 async def get_collection(db_uri: str, db_name: str, collection_name: str) -> Collection:
@@ -96,6 +133,144 @@ async def test_find_many(db_uri: str, db_name: str, collection_name: str):
             prev = doc["random"]
 
 
+
+async def test_find_options(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    sort = Document({"index": 1})
+    doc = await collection.find_one(filter=Document(), skip=4, sort=sort)
+    assert doc["index"] == 4
+
+    index = IndexModel(keys={"index": 1})
+    await collection.create_indexes([index])
+    options = FindOptions(hint="index_1", comment="test_find_options")
+    docs = await (await collection.find_many(options=options)).tolist()
+    assert len(docs) == 10
+
+    options = FindOptions(min=Document({"index": 3}), hint="index_1")
+    docs = await (await collection.find_many(options=options)).tolist()
+    assert len(docs) == 7
+
+    await collection.insert_one(Document({"name": "TEST", "index": 10}))
+    filter = Document({"name": "test"})
+    options = FindOptions(collation=Collation(locale="en", strength=2))
+    docs = await (await collection.find_many(filter, options=options)).tolist()
+    assert len(docs) == 11
+    doc = await collection.find_one(
+        filter, sort=Document({"index": -1}), options=options
+    )
+    assert doc["name"] == "TEST"
+
+    try:
+        Collation(locale="en", case_first="sideways")
+        assert False
+    except ValueError:
+        pass
+
+
+async def test_timeouts(db_uri: str, db_name: str, collection_name: str):
+    slow = Document({"$where": "sleep(50) || true"})
+
+    client = await create_client(db_uri, timeout=Timeout(1))
+    collection = client[db_name][collection_name]
+    try:
+        await (await collection.find_many(slow)).tolist()
+        assert False
+    except ValueError:
+        pass
+
+    docs = await (await collection.find_many(slow, timeout=5)).tolist()
+    assert len(docs) == 10
+
+    collection = client.database(db_name, timeout=Timeout.unbounded())[collection_name]
+    docs = await (await collection.find_many(slow)).tolist()
+    assert len(docs) == 10
+
+    collection = client[db_name].collection(collection_name, timeout=Timeout(5000))
+    doc = await collection.find_one(slow)
+    assert doc["name"] == "test"
+    try:
+        await collection.find_one(slow, timeout=Timeout(1))
+        assert False
+    except ValueError:
+        pass
+
+    assert Timeout(250).milliseconds == 250
+    assert Timeout.unbounded().milliseconds is None
+
+async def test_find_one_and_update(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    update = Document({"$set": Document({"name": "find-one-and-update"})})
+    filter = Document({"index": 1})
+
+    doc = await collection.find_one_and_update(update=update, filter=filter)
+    assert doc["name"] == "test"
+
+    update = Document({"$inc": Document({"index": 100})})
+    doc = await collection.find_one_and_update(
+        update=update, filter=filter, return_document=ReturnDocument.After
+    )
+    assert doc["index"] == 101
+    assert doc["name"] == "find-one-and-update"
+
+    filter = Document({"index": 90})
+    doc = await collection.find_one_and_update(update=update, filter=filter)
+    assert doc is None
+
+    doc = await collection.find_one_and_update(
+        update=update,
+        filter=filter,
+        upsert=True,
+        return_document=ReturnDocument.After,
+    )
+    assert doc["index"] == 190
+
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    collection = client[db_name][collection_name]
+    sort = Document({"index": -1})
+    projection = Document({"_id": 0, "index": 1})
+    doc = await collection.find_one_and_update(
+        update=Document({"$set": Document({"name": "claimed"})}),
+        filter=Document({"name": "test"}),
+        sort=sort,
+        projection=projection,
+        session=session,
+    )
+    assert "_id" not in doc
+    assert doc["index"] == 9
+
+
+async def test_find_one_and_replace(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    replacement = Document({"name": "find-one-and-replace", "index": 1})
+    filter = Document({"index": 1})
+
+    doc = await collection.find_one_and_replace(
+        replacement=replacement,
+        filter=filter,
+        return_document=ReturnDocument.After,
+    )
+    assert doc["name"] == "find-one-and-replace"
+    assert "random" not in doc
+
+    filter = Document({"index": 90})
+    doc = await collection.find_one_and_replace(replacement=replacement, filter=filter)
+    assert doc is None
+
+
+async def test_find_one_and_delete(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    sort = Document({"index": 1})
+
+    doc = await collection.find_one_and_delete(filter=Document(), sort=sort)
+    assert doc["index"] == 0
+    assert await collection.count_documents() == 9
+
+    doc = await collection.find_one_and_delete(filter=Document({"index": 90}))
+    assert doc is None
+
+
 async def test_insert_one(db_uri: str, db_name: str, collection_name: str):
     collection = await get_collection(db_uri, db_name, collection_name)
     doc = Document({"name": "insert-one"})
@@ -156,6 +331,159 @@ async def test_insert_many(db_uri: str, db_name: str, collection_name: str):
         assert str(found_doc["_id"]) in str_inserted_ids
 
 
+
+async def test_datetimes(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    local = timezone(timedelta(hours=-3))
+    aware = datetime(2024, 1, 1, 9, 30, 15, 123456, tzinfo=local)
+    await collection.insert_one(
+        Document(
+            {
+                "name": "datetimes",
+                "aware": aware,
+                "naive": datetime(2024, 1, 1, 12, 30),
+                "far": DatetimeMS(2**62),
+                "ancient": DatetimeMS(-(2**62)),
+            }
+        )
+    )
+    filter = Document({"name": "datetimes"})
+
+    doc = await collection.find_one(filter)
+    assert doc["aware"] == aware.replace(microsecond=123000)
+    assert doc["aware"].tzinfo == timezone.utc
+    assert doc["naive"] == datetime(2024, 1, 1, 12, 30, tzinfo=timezone.utc)
+    try:
+        doc["far"]
+        assert False
+    except OverflowError as e:
+        assert "'far'" in str(e)
+
+    client = await create_client(db_uri)
+    codec_options = CodecOptions(tzinfo=local, datetime_conversion="clamp")
+    db = client[db_name]
+    collection = db.collection(collection_name, codec_options=codec_options)
+    doc = await collection.find_one(filter)
+    assert doc["aware"].utcoffset() == timedelta(hours=-3)
+    assert doc["aware"].hour == 9
+    assert doc["far"].year == 9999
+    assert doc["ancient"] == datetime.min.replace(tzinfo=local)
+
+    codec_options = CodecOptions(datetime_conversion="auto")
+    collection = client.database(db_name, codec_options=codec_options)[collection_name]
+    doc = await collection.find_one(filter)
+    assert isinstance(doc["aware"], datetime)
+    assert doc["far"].millis == 2**62
+
+    codec_options = CodecOptions(datetime_conversion="ms")
+    collection = db.collection(collection_name, codec_options=codec_options)
+    doc = await collection.find_one(filter)
+    assert doc["naive"].millis == 1704112200000
+
+
+async def test_uuids(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    value = UUID("00112233-4455-6677-8899-aabbccddeeff")
+    codec_options = CodecOptions(uuid_representation="java_legacy")
+    db = client[db_name]
+    legacy = db.collection(collection_name, codec_options=codec_options)
+    await legacy.insert_one(Document({"name": "uuids", "id": value}))
+
+    doc = await legacy.find_one(Document({"id": value}))
+    assert doc["id"] == value
+
+    inserted = UUID("10112233-4455-6677-8899-aabbccddeeff")
+    result = await legacy.insert_one(Document({"_id": inserted, "name": "ids"}))
+    assert result.inserted_id == inserted
+    upserted = UUID("20112233-4455-6677-8899-aabbccddeeff")
+    update = Document({"$set": Document({"name": "ids"})})
+    filter = Document({"_id": upserted})
+    result = await legacy.update_one(update, filter, upsert=True)
+    assert result.upserted_id == upserted
+
+    codec_options = CodecOptions(uuid_representation="unspecified")
+    collection = db.collection(collection_name, codec_options=codec_options)
+    doc = await collection.find_one(Document({"name": "uuids"}))
+    assert isinstance(doc["id"], Binary)
+    assert doc["id"].value == "7766554433221100ffeeddccbbaa9988"
+    try:
+        await collection.insert_one(Document({"name": "unspecified", "id": value}))
+        assert False, "Stored a UUID without a UUID representation"
+    except ValueError as e:
+        assert "uuid_representation" in str(e)
+    explicit = Binary.from_uuid(value, "csharp_legacy")
+    await collection.insert_one(Document({"name": "unspecified", "id": explicit}))
+    doc = await collection.find_one(Document({"name": "unspecified"}))
+    assert doc["id"].value == explicit.value
+
+    explicit = Binary.from_uuid(value, "standard")
+    await legacy.insert_one(Document({"name": "explicit", "id": explicit}))
+    doc = await collection.find_one(Document({"name": "explicit"}))
+    assert str(doc["id"].subtype) == "Uuid"
+    assert doc["id"].value == "00112233445566778899aabbccddeeff"
+
+    collection = db[collection_name]
+    doc = await collection.find_one(Document({"name": "uuids"}))
+    assert isinstance(doc["id"], Binary)
+    filter = Document({"name": "uuids", "id": value})
+    assert await collection.count_documents(filter) == 0
+
+
+async def test_write_options(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    await collection.create_indexes(
+        [IndexModel(keys={"index": 1}, options=IndexOptions(unique=True))]
+    )
+
+    docs = [Document({"index": i}) for i in (9, 10, 11)]
+    try:
+        await collection.insert_many(docs, options=InsertOptions(ordered=True))
+        assert False
+    except BulkWriteError as e:
+        assert e.inserted_ids == {}
+        assert [error.index for error in e.write_errors] == [0]
+        assert e.write_errors[0].code == 11000
+    assert await collection.count_documents(Document({"index": 10})) == 0
+
+    options = InsertOptions(ordered=False, write_concern=WriteConcern(w="majority"))
+    try:
+        await collection.insert_many(docs, options=options)
+        assert False
+    except BulkWriteError as e:
+        assert sorted(e.inserted_ids.keys()) == [1, 2]
+    assert await collection.count_documents(Document({"index": 11})) == 1
+
+    options = InsertOptions(write_concern=WriteConcern(w=1, w_timeout=1000))
+    result = await collection.insert_one(Document({"index": 12}), options=options)
+    assert result.inserted_id
+
+    await collection.insert_one(Document({"name": "TEST", "index": 13}))
+    options = UpdateOptions(
+        collation=Collation(locale="en", strength=2),
+        comment="test_write_options",
+    )
+    update = Document({"$set": Document({"updated": True})})
+    filter = Document({"name": "test"})
+    result = await collection.update_many(update, filter, options=options)
+    assert result.modified_count == 11
+
+    options = UpdateOptions(hint="index_1", let_vars=Document({"target": 3}))
+    filter = Document({"$expr": Document({"$eq": ["$index", "$$target"]})})
+    result = await collection.update_one(update, filter, options=options)
+    assert result.matched_count == 1
+
+    replacement = Document({"name": "replaced", "index": 3})
+    result = await collection.replace_one(replacement, filter, options=options)
+    assert result.modified_count == 1
+
+    options = DeleteOptions(hint="index_1", write_concern=WriteConcern(journal=True))
+    result = await collection.delete_one(Document({"index": 3}), options=options)
+    assert result.deleted_count == 1
+
+    options = DeleteOptions(collation=Collation(locale="en", strength=2))
+    result = await collection.delete_many(Document({"name": "TEST"}), options=options)
+    assert result.deleted_count == 10
+
 async def test_update_one(db_uri: str, db_name: str, collection_name: str):
     collection = await get_collection(db_uri, db_name, collection_name)
     update = Document({"$set": Document({"name": "update-one-test"})})
@@ -195,6 +523,108 @@ async def test_update_one(db_uri: str, db_name: str, collection_name: str):
     assert doc["name"] == update["$set"]["name"]
 
 
+async def test_update_many(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    update = Document({"$set": Document({"name": "update-many-test"})})
+
+    filter = Document({"index": Document({"$lt": 5})})
+    result = await collection.update_many(update=update, filter=filter)
+    assert result.matched_count == 5
+    assert result.modified_count == 5
+    assert result.upserted_id is None
+
+    docs = await (await collection.find_many(filter=filter)).tolist()
+    for doc in docs:
+        assert doc["name"] == update["$set"]["name"]
+
+    update = Document({"$set": Document({"name": "upsert-many-test"})})
+    filter = Document({"index": 90})
+    result = await collection.update_many(update=update, filter=filter, upsert=True)
+    assert result.matched_count == 0
+    assert result.modified_count == 0
+    assert result.upserted_id is not None
+
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    collection = client[db_name][collection_name]
+    update = Document({"$set": Document({"name": "update-many-session-test"})})
+    filter = Document({"index": Document({"$gte": 5})})
+    result = await collection.update_many(
+        update=update, filter=filter, session=session
+    )
+    assert result.matched_count == 6
+    assert result.modified_count == 6
+    assert result.upserted_id is None
+
+
+async def test_update_pipeline(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    double = Document({"double": Document({"$multiply": ["$index", 2]})})
+    pipeline = [Document({"$set": double})]
+
+    result = await collection.update_many(update=pipeline, filter=Document())
+    assert result.modified_count == 10
+    doc = await collection.find_one(Document({"index": 4}))
+    assert doc["double"] == 8
+
+    double = Document({"double": Document({"$add": ["$double", 1]})})
+    pipeline = [Document({"$set": double})]
+    filter = Document({"index": 4})
+    result = await collection.update_one(update=pipeline, filter=filter)
+    assert result.modified_count == 1
+    doc = await collection.find_one_and_update(
+        pipeline, filter, return_document=ReturnDocument.After
+    )
+    assert doc["double"] == 10
+
+    result = await collection.bulk_write([UpdateOne(filter, pipeline)])
+    assert result.modified_count == 1
+
+    try:
+        UpdateMany(filter, [Document({"double": 0})])
+        assert False
+    except ValueError:
+        pass
+
+
+async def test_replace_one(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    replacement = Document({"name": "replace-one-test", "index": 1})
+
+    filter = Document({"index": 1})
+    result = await collection.replace_one(replacement=replacement, filter=filter)
+    assert result.matched_count == 1
+    assert result.modified_count == 1
+    assert result.upserted_id is None
+
+    doc = await collection.find_one(filter=filter)
+    assert doc["name"] == replacement["name"]
+    assert "random" not in doc
+
+    replacement = Document({"name": "upsert-replace-test", "index": 90})
+    filter = Document({"index": 90})
+    result = await collection.replace_one(
+        replacement=replacement, filter=filter, upsert=True
+    )
+    assert result.matched_count == 0
+    assert result.modified_count == 0
+    assert result.upserted_id is not None
+
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    collection = client[db_name][collection_name]
+    replacement = Document({"name": "replace-one-session-test", "index": 2})
+    filter = Document({"index": 2})
+    result = await collection.replace_one(
+        replacement=replacement, filter=filter, session=session
+    )
+    assert result.matched_count == 1
+    assert result.modified_count == 1
+
+    doc = await collection.find_one(filter=filter)
+    assert doc["name"] == replacement["name"]
+
+
 async def test_delete_one(db_uri: str, db_name: str, collection_name: str):
     collection = await get_collection(db_uri, db_name, collection_name)
     docs = await (await collection.find_many()).tolist()
@@ -239,6 +669,456 @@ async def test_delete_many(db_uri: str, db_name: str, collection_name: str):
     assert new_count == 0
 
 
+async def test_bulk_write(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    requests = [
+        InsertOne(Document({"name": "bulk", "index": 10})),
+        UpdateOne(
+            Document({"index": 0}), Document({"$set": Document({"name": "bulk"})})
+        ),
+        UpdateMany(
+            Document({"index": Document({"$gte": 5})}),
+            Document({"$set": Document({"name": "bulk-many"})}),
+        ),
+        ReplaceOne(Document({"index": 90}), Document({"index": 90}), upsert=True),
+        DeleteOne(Document({"index": 1})),
+        DeleteMany(Document({"index": Document({"$in": [2, 3]})})),
+    ]
+    result = await collection.bulk_write(requests)
+    assert result.inserted_count == 1
+    assert result.matched_count == 7
+    assert result.modified_count == 7
+    assert result.upserted_count == 1
+    assert result.deleted_count == 3
+    assert list(result.inserted_ids.keys()) == [0]
+    assert list(result.upserted_ids.keys()) == [3]
+    assert result.write_errors == []
+
+    doc = await collection.find_one(Document({"index": 90}))
+    assert str(doc["_id"]) == str(result.upserted_ids[3])
+
+    try:
+        UpdateOne(Document(), Document({"name": "no-operator"}))
+        assert False, "Update without operators should be rejected."
+    except ValueError:
+        pass
+
+
+async def test_bulk_write_errors(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    existing = await collection.find_one(Document({"index": 0}))
+    requests = [
+        InsertOne(Document({"name": "bulk-error-0"})),
+        InsertOne(Document({"_id": existing["_id"]})),
+        InsertOne(Document({"name": "bulk-error-2"})),
+    ]
+
+    try:
+        await collection.bulk_write(requests)
+        assert False, "Bulk write with a duplicate key did not raise"
+    except BulkWriteError as e:
+        assert e.result.inserted_count == 1
+        assert list(e.inserted_ids.keys()) == [0]
+        assert len(e.write_errors) == 1
+        assert e.write_errors[0].index == 1
+        assert e.write_errors[0].code == 11000
+        assert e.result.write_errors[0].index == 1
+
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    collection = client[db_name][collection_name]
+    try:
+        await collection.bulk_write(requests, ordered=False, session=session)
+        assert False, "Bulk write with a duplicate key did not raise"
+    except BulkWriteError as e:
+        assert e.result.inserted_count == 2
+        assert sorted(e.inserted_ids.keys()) == [0, 2]
+        assert len(e.write_errors) == 1
+        assert e.write_errors[0].index == 1
+
+    try:
+        await collection.bulk_write([])
+        assert False, "Bulk write without requests did not raise"
+    except ValueError:
+        pass
+
+
+async def test_errors(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    existing = await collection.find_one(Document({"index": 0}))
+    try:
+        await collection.insert_one(Document({"_id": existing["_id"]}))
+        assert False
+    except DuplicateKeyError as e:
+        assert isinstance(e, WriteError)
+        assert isinstance(e, ValueError)
+        assert e.code == 11000
+        assert e.error_labels == []
+
+    try:
+        await collection.update_one(
+            Document({"index": 0}), Document({"$inc": Document({"name": 1})})
+        )
+        assert False
+    except WriteError as e:
+        assert not isinstance(e, DuplicateKeyError)
+        assert e.code == 14
+        assert e.code_name == "TypeMismatch"
+
+    client = await create_client("mongodb://localhost:1/?serverSelectionTimeoutMS=100")
+    try:
+        await client[db_name][collection_name].find_one(Document())
+        assert False
+    except ServerSelectionTimeout as e:
+        assert isinstance(e, ConnectionFailure)
+        assert e.code is None
+
+
+async def test_aggregate(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    pipeline = [Document({"$sort": Document({"index": -1})})]
+    options = AggregateOptions(allow_disk_use=True, comment="test_aggregate")
+    docs = await (await collection.aggregate(pipeline, options=options)).tolist()
+    assert len(docs) == 10
+    assert docs[0]["index"] == 9
+
+    expr = Document({"$expr": Document({"$lt": ["$index", "$$bound"]})})
+    pipeline = [Document({"$match": expr})]
+    options = AggregateOptions(let_vars=Document({"bound": 4}))
+    docs = await (await collection.aggregate(pipeline, options=options)).tolist()
+    assert len(docs) == 4
+
+    await collection.insert_one(Document({"name": "TEST", "index": 10}))
+    pipeline = [Document({"$match": Document({"name": "test"})})]
+    options = AggregateOptions(collation=Collation(locale="en", strength=2))
+    docs = await (await collection.aggregate(pipeline, options=options)).tolist()
+    assert len(docs) == 11
+
+    out_name = f"{collection_name}-out"
+    pipeline = [Document({"$out": out_name})]
+    options = AggregateOptions(
+        bypass_document_validation=True,
+        write_concern=WriteConcern(w=1, journal=True),
+    )
+    await (await collection.aggregate(pipeline, options=options)).tolist()
+    out = await get_collection(db_uri, db_name, out_name)
+    assert await out.count_documents() == 11
+    await out.drop()
+
+    formatter = lambda x: x["index"]
+    pipeline = [Document({"$sort": Document({"index": 1})})]
+    docs = await collection.aggregate(pipeline, formatter=formatter)
+    assert await docs.tolist() == list(range(11))
+
+
+async def test_cursor_errors(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    # Fails on the document with index 5, which is only fetched by a later batch
+    divide = Document({"$divide": [1, Document({"$subtract": ["$index", 5]})]})
+    pipeline = [
+        Document({"$sort": Document({"index": 1})}),
+        Document({"$project": Document({"x": divide})}),
+    ]
+    docs = []
+    try:
+        async for doc in await collection.aggregate(pipeline, batch_size=2):
+            docs.append(doc)
+        assert False, "Cursor error was not raised"
+    except ValueError:
+        pass
+    assert len(docs) == 4
+
+    cursor = await collection.find_many(Document())
+    assert len([doc async for doc in cursor]) == 10
+    assert [doc async for doc in cursor] == []
+
+    indexes = [index async for index in await collection.list_indexes()]
+    assert indexes[0].options.name == "_id_"
+
+
+async def test_cursor_batches(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    cursor = await collection.find_many(Document(), sort=Document(index=1))
+    batch = await cursor.next_batch(4)
+    assert [doc["index"] for doc in batch] == [0, 1, 2, 3]
+    batch = await cursor.next_batch(4)
+    assert [doc["index"] for doc in batch] == [4, 5, 6, 7]
+    assert len(await cursor.next_batch(4)) == 2
+    assert await cursor.next_batch(4) == []
+
+    formatter = lambda x: x["index"]
+    cursor = await collection.find_many(Document(), formatter=formatter)
+    assert len(await cursor.to_list(max_length=3)) == 3
+    assert len(await cursor.to_list()) == 7
+
+    cursor = await collection.find_many(Document())
+    try:
+        await cursor.to_list(max_bytes=100)
+        assert False, "Collected documents beyond the memory cap"
+    except MemoryError:
+        pass
+
+
+async def test_cursor_lifecycle(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    cursor = await collection.find_many(Document(), batch_size=2)
+    assert cursor.id != 0
+    assert cursor.alive
+    assert await cursor.has_next()
+    assert await cursor.has_next()
+    assert len(await cursor.next_batch(3)) == 3
+    await cursor.close()
+    assert not cursor.alive
+    assert not await cursor.has_next()
+    assert await cursor.tolist() == []
+
+    cursor = await collection.find_many(Document())
+    assert cursor.id == 0
+    assert len(await cursor.tolist()) == 10
+    assert not await cursor.has_next()
+    assert not cursor.alive
+
+    async with await collection.find_many(Document(), batch_size=2) as cursor:
+        doc = await anext(cursor)
+        assert doc["name"] == "test"
+    assert not cursor.alive
+
+    async with await collection.list_indexes() as indexes:
+        assert indexes.id == 0
+        assert await indexes.has_next()
+    assert not indexes.alive
+
+
+async def test_prefetch(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    sort = Document(index=1)
+    cursor = await collection.find_many(sort=sort, batch_size=3, prefetch=4)
+    assert [doc["index"] async for doc in cursor] == list(range(10))
+    assert not cursor.alive
+
+    cursor = await collection.find_many(batch_size=2, prefetch=2)
+    assert len(await cursor.next_batch(3)) == 3
+    await cursor.close()
+    assert await cursor.tolist() == []
+
+    pipeline = [Document({"$sort": Document({"index": -1})})]
+    cursor = await collection.aggregate(pipeline, batch_size=2, prefetch=1)
+    docs = await cursor.tolist()
+    assert [doc["index"] for doc in docs] == list(range(9, -1, -1))
+
+    session = await (await create_client(db_uri)).create_session()
+    cursor = await collection.find_many(session=session, batch_size=2, prefetch=8)
+    assert len(await cursor.tolist()) == 10
+
+    divide = Document({"$divide": [1, Document({"$subtract": ["$index", 5]})]})
+    pipeline = [
+        Document({"$sort": Document({"index": 1})}),
+        Document({"$project": Document({"x": divide})}),
+    ]
+    cursor = await collection.aggregate(pipeline, batch_size=2, prefetch=4)
+    try:
+        await cursor.tolist()
+        assert False, "Prefetched cursor error was not raised"
+    except ValueError:
+        pass
+
+
+async def test_tailable_cursor(db_uri: str, db_name: str, collection_name: str):
+    collection = await get_collection(db_uri, db_name, collection_name)
+
+    options = FindOptions(cursor_type=CursorType.TailableAwait, max_await_time=100)
+    assert options.cursor_type == CursorType.TailableAwait
+    assert options.max_await_time == 100
+
+    # Tailable cursors are only allowed on capped collections
+    for cursor_type in (CursorType.Tailable, CursorType.TailableAwait):
+        try:
+            options = FindOptions(cursor_type=cursor_type, max_await_time=100)
+            await collection.find_many(options=options)
+            assert False, "Opened a tailable cursor on a collection that is not capped"
+        except ValueError:
+            pass
+
+    options = FindOptions(cursor_type=CursorType.NonTailable)
+    cursor = await collection.find_many(options=options)
+    assert len(await cursor.tolist()) == 10
+
+    db = (await create_client(db_uri))[db_name]
+    capped_name = f"{collection_name}-capped"
+    await db[capped_name].drop()
+    capped = await db.create_collection(capped_name, capped=True, size=4096)
+    await capped.insert_one(Document(index=0))
+
+    options = FindOptions(cursor_type=CursorType.TailableAwait, max_await_time=100)
+    cursor = await capped.find_many(options=options)
+    assert (await anext(cursor))["index"] == 0
+
+    # The cursor keeps waiting through the empty batches returned every 100ms
+    next_doc = asyncio.ensure_future(anext(cursor))
+    await asyncio.sleep(0.5)
+    assert not next_doc.done()
+    assert cursor.alive
+
+    await capped.insert_one(Document(index=1))
+    doc = await asyncio.wait_for(next_doc, timeout=5)
+    assert doc["index"] == 1
+
+    # Closing stops an iteration waiting for new documents
+    next_doc = asyncio.ensure_future(anext(cursor))
+    await asyncio.sleep(0.2)
+    await asyncio.wait_for(cursor.close(), timeout=5)
+    try:
+        await asyncio.wait_for(next_doc, timeout=5)
+        assert False, "Closed tailable cursor returned a document"
+    except StopAsyncIteration:
+        pass
+    assert not cursor.alive
+
+
+async def test_watch(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    collection = client[db_name][collection_name]
+
+    options = ChangeStreamOptions(full_document="updateLookup")
+    stream = await collection.watch(options=options)
+    assert stream.alive
+    result = await collection.insert_one(Document(name="watched", index=10))
+    await collection.update_one(
+        Document(_id=result.inserted_id), Document({"$set": Document(index=11)})
+    )
+
+    event = await anext(stream)
+    assert event.operation_type == "insert"
+    assert event.ns.db == db_name
+    assert event.ns.coll == collection_name
+    assert event.document_key["_id"] == result.inserted_id
+    assert event.full_document["name"] == "watched"
+    assert event.cluster_time is not None
+    assert event.wall_time is not None
+    insert_token = event.resume_token
+
+    event = await anext(stream)
+    assert event.operation_type == "update"
+    assert event.update_description.updated_fields["index"] == 11
+    assert event.update_description.removed_fields == []
+    assert event.full_document["index"] == 11
+    assert stream.resume_token is not None
+    await stream.close()
+    assert not stream.alive
+
+    for options in (
+        ChangeStreamOptions(resume_after=insert_token),
+        ChangeStreamOptions(start_after=insert_token),
+    ):
+        async with await collection.watch(options=options) as stream:
+            event = await anext(stream)
+            assert event.operation_type == "update"
+
+    session = await client.create_session()
+    await collection.delete_one(Document(_id=result.inserted_id), session=session)
+    operation_time = await session.operation_time()
+    options = ChangeStreamOptions(start_at_operation_time=operation_time)
+    stream = await collection.watch(options=options, session=session)
+    event = await anext(stream)
+    assert event.operation_type == "delete"
+    assert event.full_document is None
+
+    pipeline = [Document({"$match": Document(operationType="insert")})]
+    stream = await collection.watch(pipeline=pipeline)
+    await collection.delete_many(Document())
+    await collection.insert_one(Document(name="matched"))
+    event = await anext(stream)
+    assert event.operation_type == "insert"
+    assert event.full_document["name"] == "matched"
+
+    # Closing stops an iteration waiting for events
+    next_event = asyncio.ensure_future(anext(stream))
+    await asyncio.sleep(0.2)
+    await asyncio.wait_for(stream.close(), timeout=5)
+    try:
+        await asyncio.wait_for(next_event, timeout=5)
+        assert False, "Closed change stream returned an event"
+    except StopAsyncIteration:
+        pass
+    assert not stream.alive
+
+    try:
+        ChangeStreamOptions(full_document="always")
+        assert False, "Created change stream options with an invalid mode"
+    except ValueError:
+        pass
+    try:
+        ChangeStreamOptions(start_at_operation_time=10)
+        assert False, "Created change stream options with an integer operation time"
+    except TypeError:
+        pass
+
+
+async def test_watch_with_checkpoints(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    collection = client[db_name][collection_name]
+    checkpoints = client[db_name]["checkpoints"]
+    await checkpoints.drop()
+
+    async def consumer():
+        return await collection.watch_with_checkpoints(
+            checkpoints, "consumer", checkpoint_every=2, checkpoint_interval=None
+        )
+
+    stream = await consumer()
+    await collection.insert_many([Document(index=i) for i in range(10, 15)])
+    events = [await anext(stream) for _ in range(3)]
+    assert [e.full_document["index"] for e in events] == [10, 11, 12]
+    # Only the events before the last one requested count as processed
+    await stream.close()
+    assert not stream.alive
+    checkpoint = await checkpoints.find_one(Document(_id="consumer"))
+    assert checkpoint["resume_token"] is not None
+
+    async with await consumer() as stream:
+        event = await anext(stream)
+        assert event.full_document["index"] == 12
+        events = []
+        while len(events) < 2:
+            events.extend(await stream.next_batch(2))
+        assert [e.full_document["index"] for e in events] == [13, 14]
+
+    await collection.insert_one(Document(index=15))
+    try:
+        async with await consumer() as stream:
+            event = await anext(stream)
+            assert event.full_document["index"] == 15
+            raise RuntimeError()
+    except RuntimeError:
+        pass
+
+    stream = await consumer()
+    event = await anext(stream)
+    assert event.full_document["index"] == 15
+    await stream.checkpoint()
+    await stream.close()
+
+    await collection.insert_one(Document(index=16))
+    stream = await consumer()
+    event = await anext(stream)
+    assert event.full_document["index"] == 16
+    next_event = asyncio.ensure_future(anext(stream))
+    await asyncio.sleep(0.2)
+    await asyncio.wait_for(stream.close(), timeout=5)
+    try:
+        await asyncio.wait_for(next_event, timeout=5)
+        assert False, "Closed change stream returned an event"
+    except StopAsyncIteration:
+        pass
+    assert not stream.alive
+    await checkpoints.drop()
+
+
 async def test_distinct(db_uri: str, db_name: str, collection_name: str):
     collection = await get_collection(db_uri, db_name, collection_name)
     result = await collection.distinct(field_name="random")
@@ -298,6 +1178,27 @@ async def test_drop_indexes(db_uri: str, db_name: str, collection_name: str):
     assert len(indexes) == 1  # _id cannot be dropped
 
 
+
+async def test_indexes_with_session(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    collection = client[db_name][collection_name]
+
+    index_options = IndexOptions(name="test-session-indexes")
+    index = IndexModel(keys={"index": 1}, options=index_options)
+    result = await collection.create_indexes(indexes=[index], session=session)
+    assert result.index_names == ["test-session-indexes"]
+
+    indexes = await (await collection.list_indexes(session=session)).tolist()
+    assert len(indexes) == 2
+
+    await collection.drop_indexes(["test-session-indexes"], session=session)
+    indexes = await (await collection.list_indexes(session=session)).tolist()
+    assert len(indexes) == 1
+
+    await collection.drop(session=session)
+    assert await collection.count_documents(session=session) == 0
+
 async def test_count_documents(db_uri: str, db_name: str, collection_name: str):
     collection = await get_collection(db_uri, db_name, collection_name)
     docs = await collection.count_documents()
@@ -307,3 +1208,32 @@ async def test_count_documents(db_uri: str, db_name: str, collection_name: str):
     filter["index"] = 0
     docs = await collection.count_documents(filter=filter)
     assert docs == 1
+
+    assert await collection.count_documents(skip=2, limit=5) == 5
+    assert await collection.count_documents(skip=8, limit=5) == 2
+
+    await collection.insert_one(Document({"name": "TEST", "index": 10}))
+    filter = Document({"name": "test"})
+    options = CountOptions(
+        collation=Collation(locale="en", strength=2),
+        read_concern=ReadConcern("local"),
+    )
+    assert await collection.count_documents(filter, options=options) == 11
+
+    options = CountOptions(hint="_id_")
+    assert await collection.count_documents(options=options) == 11
+
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    collection = client[db_name][collection_name]
+    assert await collection.count_documents(filter, session=session) == 10
+
+
+async def test_estimated_document_count(
+    db_uri: str, db_name: str, collection_name: str
+):
+    collection = await get_collection(db_uri, db_name, collection_name)
+    assert await collection.estimated_document_count() == 10
+
+    collection = await get_collection(db_uri, db_name, f"{collection_name}-empty")
+    assert await collection.estimated_document_count(timeout=1) == 0
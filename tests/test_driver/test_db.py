@@ -1,4 +1,5 @@
 from ruson.driver.client import create_client
+from ruson.driver.types import Document
 
 
 async def test_list_collections(
@@ -13,6 +14,10 @@ async def test_list_collections(
     if collection_name not in collections:
         assert False, f"Excpected collection {collection_name} not found"
 
+    session = await client.create_session()
+    collections = await db.list_collections(session=session)
+    assert collection_name in collections
+
 
 async def test_drop(db_uri: str, db_name: str):
     client = await create_client(db_uri)
@@ -21,3 +26,28 @@ async def test_drop(db_uri: str, db_name: str):
         await db.drop()
     except Exception as e:
         assert False, f"Failed to drop database with exception: {e}"
+
+
+async def test_drop_with_session(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    session = await client.create_session()
+    db = client.database(db_name)
+    await db.drop(session=session)
+    assert collection_name not in await db.list_collections(session=session)
+
+
+async def test_watch(db_uri: str, db_name: str, collection_name: str):
+    client = await create_client(db_uri)
+    db = client.database(db_name)
+    stream = await db.watch()
+    await db.collection(collection_name).insert_one(Document(name="first"))
+    await db.collection("other_collection").insert_one(Document(name="second"))
+
+    event = await anext(stream)
+    assert event.ns.coll == collection_name
+    assert event.full_document["name"] == "first"
+    event = await anext(stream)
+    assert event.ns.coll == "other_collection"
+    assert event.full_document["name"] == "second"
+    await stream.close()
+    await db.collection("other_collection").drop()
@@ -0,0 +1,85 @@
+from datetime import datetime, timezone
+from decimal import Decimal
+from uuid import UUID
+
+from ruson.driver.types import Binary, Decimal128, Document, ObjectId
+
+
+async def test_conversion_errors():
+    try:
+        Document({"items": [1, object()]})
+        assert False
+    except TypeError as e:
+        assert "'items.1'" in str(e)
+
+    try:
+        Document(count=2**64)
+        assert False
+    except OverflowError as e:
+        assert "'count'" in str(e)
+
+    try:
+        ObjectId.from_str("not-an-object-id")
+        assert False
+    except ValueError:
+        pass
+
+    oid = ObjectId()
+    assert str(ObjectId.from_str(str(oid))) == str(oid)
+
+
+async def test_datetime_before_epoch():
+    value = datetime(1950, 6, 1, 12, 30, tzinfo=timezone.utc)
+    doc = Document({"created": value})
+    assert doc["created"] == value
+    assert doc.as_dict() == {"created": value}
+
+
+async def test_decimal128():
+    values = ["19.90", "-0", "0E-6176", "1E+6111", "1E-6176", "-1.5E-10", "0.000001"]
+    values += ["Infinity", "-Infinity", "NaN", "-NaN", "sNaN", "-sNaN"]
+    for value in values:
+        decimal = Decimal128(value).to_decimal()
+        assert str(decimal) == str(Decimal(value))
+    assert Decimal128("1E+6144").to_decimal() == Decimal("1E+6144")
+    assert Decimal128("0E-9999").to_decimal() == Decimal("0")
+    for value in ["12345678901234567890123456789012345", "1E+6145", "1E+99999"]:
+        try:
+            Decimal128(value)
+            assert False, f"Encoded {value} inexactly"
+        except ValueError:
+            pass
+    assert Decimal128(Decimal("NaN")).to_decimal().is_qnan()
+    assert Decimal128(Decimal("-sNaN")).to_decimal().is_snan()
+    assert str(Decimal128("1.50")) == "1.50"
+
+    doc = Document(price=Decimal("12.30"))
+    assert doc["price"].to_decimal() == Decimal("12.30")
+
+    try:
+        Document({"prices": [Decimal("1E-7000")]})
+        assert False
+    except ValueError as e:
+        assert "'prices.0'" in str(e)
+
+
+async def test_uuid():
+    value = UUID("00112233-4455-6677-8899-aabbccddeeff")
+    doc = Document(id=value)
+    assert doc["id"] == value
+    doc = Document(ids=[Document(id=value)])
+    assert doc["ids"][0]["id"] == value
+    explicit = Binary.from_uuid(value, "python_legacy")
+    assert Document(id=explicit)["id"].value == explicit.value
+
+    binary = Binary.from_uuid(value, "java_legacy")
+    assert str(binary.subtype) == "UuidOld"
+    assert binary.value == "7766554433221100ffeeddccbbaa9988"
+    assert binary.as_uuid("java_legacy") == value
+    assert Binary.from_uuid(value, "csharp_legacy").as_uuid("csharp_legacy") == value
+
+    try:
+        binary.as_uuid("standard")
+        assert False
+    except ValueError:
+        pass